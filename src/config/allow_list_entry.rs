@@ -0,0 +1,63 @@
+//! Time-boxed exceptions to crate risk gating.
+
+use chrono::{Duration, NaiveDate};
+use semver::{Version, VersionReq};
+use serde::{Deserialize, Serialize};
+
+/// An allow-list exemption for a crate+version that would otherwise be flagged by
+/// `medium_risk_threshold`/`low_risk_threshold`, documented with a reason and (optionally) an
+/// expiry date so a temporarily-accepted risky dependency doesn't quietly become a permanent
+/// bypass, mirroring the waiver records kept by tools like `cargo vet`.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct AllowListEntry {
+    /// The crate name this entry applies to. A trailing `*` is treated as a wildcard
+    /// matching any suffix (e.g. `"tokio-*"` matches `tokio-util`, `tokio-stream`, ...);
+    /// anything else is matched exactly.
+    pub name: String,
+
+    /// A semver version requirement (e.g. "^1.0", ">=2.0, <3.0", "=1.2.3", "*")
+    pub version: VersionReq,
+
+    /// Why this exception exists, so reviewers reading the config understand what's being
+    /// waived and why.
+    #[serde(default)]
+    pub reason: Option<String>,
+
+    /// The date on which this entry stops applying. `None` means it never expires;
+    /// [`Config::allow_list_warnings`](crate::config::Config::allow_list_warnings) only reports
+    /// dated entries, so an un-dated waiver produces no warning.
+    #[serde(default)]
+    pub expires: Option<NaiveDate>,
+}
+
+impl AllowListEntry {
+    /// Check if this entry matches the given crate name and version, and hasn't expired as
+    /// of `today`.
+    #[must_use]
+    pub fn matches(&self, name: &str, version: &Version, today: NaiveDate) -> bool {
+        if self.is_expired(today) {
+            return false;
+        }
+
+        name_matches(&self.name, name) && self.version.matches(version)
+    }
+
+    /// Whether this entry has already expired as of `today`.
+    #[must_use]
+    pub fn is_expired(&self, today: NaiveDate) -> bool {
+        self.expires.is_some_and(|expires| today >= expires)
+    }
+
+    /// Whether this entry is still active but will expire within `window_days` of `today`.
+    #[must_use]
+    pub fn expires_soon(&self, today: NaiveDate, window_days: u32) -> bool {
+        self.expires
+            .is_some_and(|expires| !self.is_expired(today) && expires <= today + Duration::days(i64::from(window_days)))
+    }
+}
+
+/// Match `name` against `pattern`, where a trailing `*` in `pattern` matches any suffix.
+fn name_matches(pattern: &str, name: &str) -> bool {
+    pattern.strip_suffix('*').map_or(pattern == name, |prefix| name.starts_with(prefix))
+}
@@ -0,0 +1,829 @@
+//! Root configuration type, parsing, and schema versioning.
+
+use crate::config::AllowListEntry;
+use crate::config::Color;
+use crate::config::policies::{
+    AgePolicy, AgedCountPolicy, AuditCoveragePolicy, BooleanPolicy, LicensePolicy, MaintenanceStatusPolicy, MaxCountPolicy,
+    MaxScorePolicy, MinCountPolicy, MinScorePolicy, MsrvPolicy, PercentagePolicy, ResponsivenessPolicy, ReverseDepsPolicy,
+    ScriptPolicy, VersionPolicy,
+};
+use crate::config::{Diagnostic, Policy, Severity};
+use crate::metrics::Metric;
+use crate::misc::{Profile, RequestLogging};
+use crate::ranking::RiskLevel;
+use chrono::Utc;
+use core::time::Duration;
+use palette::Srgb;
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use serde_yaml::Value;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// Newest configuration schema version understood by this build.
+///
+/// Bump this whenever the policy grammar changes in a way that isn't backward
+/// compatible, and add a migration arm in [`Config::from_yaml`] for reading the
+/// previous version's documents into the current shape.
+pub const CURRENT_VERSION: u32 = 1;
+
+const fn default_version() -> u32 {
+    1
+}
+
+const fn default_max_trust_distance() -> u32 {
+    2
+}
+
+/// Default stroke color for the HTML report's download-history sparkline: a muted blue.
+fn default_sparkline_color() -> Color {
+    Color(Srgb::new(0x34, 0x98, 0xdb))
+}
+
+const fn default_medium_risk_threshold() -> f64 {
+    30.0
+}
+
+const fn default_low_risk_threshold() -> f64 {
+    70.0
+}
+
+const fn default_cache_ttl() -> Duration {
+    Duration::from_secs(60 * 60 * 24 * 7)
+}
+
+const fn default_prod_minimum_medium_risk_threshold() -> f64 {
+    50.0
+}
+
+const fn default_prod_minimum_low_risk_threshold() -> f64 {
+    80.0
+}
+
+const fn default_prod_maximum_cache_ttl() -> Duration {
+    Duration::from_secs(60 * 60 * 24)
+}
+
+const fn default_allow_list_expiry_warning_days() -> u32 {
+    14
+}
+
+/// Embedded starter configuration, written out by `cargo rank init`.
+pub const DEFAULT_CONFIG_YAML: &str = include_str!("default_config.yaml");
+
+/// Root configuration for crate policy evaluation.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Schema version of this configuration file.
+    ///
+    /// Absent in files written before versioning was introduced, in which case it
+    /// defaults to `1` so existing configs keep working unchanged.
+    #[serde(default = "default_version")]
+    pub version: u32,
+
+    #[serde(default)]
+    pub license: Vec<LicensePolicy>,
+    #[serde(default)]
+    pub age: Vec<AgePolicy>,
+    #[serde(default)]
+    pub min_version: Vec<VersionPolicy>,
+    #[serde(default)]
+    pub msrv: Vec<MsrvPolicy>,
+    #[serde(default)]
+    pub release_count: Vec<AgedCountPolicy>,
+    #[serde(default)]
+    pub audit_coverage: Vec<AuditCoveragePolicy>,
+    #[serde(default)]
+    pub dependency_freshness: Vec<PercentagePolicy>,
+    #[serde(default)]
+    pub maintenance_status: Vec<MaintenanceStatusPolicy>,
+
+    #[serde(default)]
+    pub overall_download_count: Vec<MinCountPolicy>,
+    #[serde(default)]
+    pub one_month_download_count: Vec<MinCountPolicy>,
+    #[serde(default)]
+    pub adjusted_monthly_downloads: Vec<MinCountPolicy>,
+    /// Minimum percentage growth (can be negative for a declining crate) between the
+    /// trailing and prior three-month download averages.
+    #[serde(default)]
+    pub download_trend: Vec<MinScorePolicy>,
+
+    #[serde(default)]
+    pub overall_owner_count: Vec<MinCountPolicy>,
+    #[serde(default)]
+    pub team_owner_count: Vec<MinCountPolicy>,
+    #[serde(default)]
+    pub user_owner_count: Vec<MinCountPolicy>,
+
+    #[serde(default)]
+    pub direct_dependency_count: Vec<MaxCountPolicy>,
+    #[serde(default)]
+    pub dependent_count: Vec<MinCountPolicy>,
+    #[serde(default)]
+    pub required_reverse_dependency_count: Vec<ReverseDepsPolicy>,
+
+    #[serde(default)]
+    pub tarball_size: Vec<MaxCountPolicy>,
+    #[serde(default)]
+    pub uncompressed_size: Vec<MaxCountPolicy>,
+    #[serde(default)]
+    pub dependency_weight: Vec<MaxCountPolicy>,
+    #[serde(default)]
+    pub installed_with_deps_size: Vec<MaxCountPolicy>,
+    #[serde(default)]
+    pub minimal_dependency_footprint: Vec<MaxCountPolicy>,
+    #[serde(default)]
+    pub lines_of_code: Vec<MaxCountPolicy>,
+
+    #[serde(default)]
+    pub doc_coverage_percentage: Vec<PercentagePolicy>,
+    #[serde(default)]
+    pub broken_doc_link_count: Vec<MaxCountPolicy>,
+    #[serde(default)]
+    pub code_coverage_percentage: Vec<PercentagePolicy>,
+    #[serde(default)]
+    pub fully_safe_code: Vec<BooleanPolicy>,
+    /// Lines of non-Rust source (e.g. vendored C/C++ or assembly) shipped alongside the crate.
+    #[serde(default)]
+    pub non_rust_language_line_count: Vec<MaxCountPolicy>,
+    #[serde(default)]
+    pub transitive_dependency_count: Vec<MaxCountPolicy>,
+    #[serde(default)]
+    pub example_count: Vec<MinCountPolicy>,
+    #[serde(default)]
+    pub comment_ratio: Vec<PercentagePolicy>,
+
+    #[serde(default)]
+    pub repo_contributor_count: Vec<MinCountPolicy>,
+    #[serde(default)]
+    pub repo_star_count: Vec<MinCountPolicy>,
+    #[serde(default)]
+    pub repo_fork_count: Vec<MinCountPolicy>,
+    #[serde(default)]
+    pub repo_subscriber_count: Vec<MinCountPolicy>,
+    #[serde(default)]
+    pub commit_activity: Vec<AgedCountPolicy>,
+
+    #[serde(default)]
+    pub open_issue_count: Vec<MaxCountPolicy>,
+    #[serde(default)]
+    pub closed_issue_count: Vec<MinCountPolicy>,
+    #[serde(default)]
+    pub issue_responsiveness: Vec<ResponsivenessPolicy>,
+    #[serde(default)]
+    pub open_pull_request_count: Vec<MaxCountPolicy>,
+    #[serde(default)]
+    pub closed_pull_request_count: Vec<MinCountPolicy>,
+    #[serde(default)]
+    pub pull_request_responsiveness: Vec<ResponsivenessPolicy>,
+
+    /// When set, a package only reachable through a non-default/optional feature (i.e. no
+    /// edge exists for it under the enabled feature set) is excluded from
+    /// `vulnerability_count` and the severity-specific vulnerability counts below.
+    #[serde(default)]
+    pub only_count_reachable_vulnerabilities: bool,
+
+    #[serde(default)]
+    pub vulnerability_count: Vec<MaxCountPolicy>,
+    #[serde(default)]
+    pub low_vulnerability_count: Vec<MaxCountPolicy>,
+    #[serde(default)]
+    pub medium_vulnerability_count: Vec<MaxCountPolicy>,
+    #[serde(default)]
+    pub high_vulnerability_count: Vec<MaxCountPolicy>,
+    #[serde(default)]
+    pub critical_vulnerability_count: Vec<MaxCountPolicy>,
+    #[serde(default)]
+    pub cvss_weighted_vulnerability_score: Vec<MaxScorePolicy>,
+    #[serde(default)]
+    pub warning_count: Vec<MaxCountPolicy>,
+    #[serde(default)]
+    pub notice_warning_count: Vec<MaxCountPolicy>,
+    #[serde(default)]
+    pub unmaintained_warning_count: Vec<MaxCountPolicy>,
+    #[serde(default)]
+    pub unsound_warning_count: Vec<MaxCountPolicy>,
+    #[serde(default)]
+    pub yanked_warning_count: Vec<MaxCountPolicy>,
+
+    #[serde(default)]
+    pub historical_vulnerability_count: Vec<MaxCountPolicy>,
+    #[serde(default)]
+    pub historical_low_vulnerability_count: Vec<MaxCountPolicy>,
+    #[serde(default)]
+    pub historical_medium_vulnerability_count: Vec<MaxCountPolicy>,
+    #[serde(default)]
+    pub historical_high_vulnerability_count: Vec<MaxCountPolicy>,
+    #[serde(default)]
+    pub historical_critical_vulnerability_count: Vec<MaxCountPolicy>,
+    #[serde(default)]
+    pub historical_cvss_weighted_vulnerability_score: Vec<MaxScorePolicy>,
+    #[serde(default)]
+    pub historical_warning_count: Vec<MaxCountPolicy>,
+    #[serde(default)]
+    pub historical_notice_warning_count: Vec<MaxCountPolicy>,
+    #[serde(default)]
+    pub historical_unmaintained_warning_count: Vec<MaxCountPolicy>,
+    #[serde(default)]
+    pub historical_unsound_warning_count: Vec<MaxCountPolicy>,
+    #[serde(default)]
+    pub historical_yanked_warning_count: Vec<MaxCountPolicy>,
+    #[serde(default)]
+    pub advisory_patch_responsiveness: Vec<ResponsivenessPolicy>,
+
+    /// cargo-crev identity IDs treated as trust roots when computing which reviewers are
+    /// trusted for the review metrics below.
+    #[serde(default)]
+    pub trusted_review_roots: Vec<String>,
+    /// Maximum cumulative trust distance from a root before an identity stops being trusted.
+    #[serde(default = "default_max_trust_distance")]
+    pub max_trust_distance: u32,
+
+    #[serde(default)]
+    pub trusted_review_count: Vec<MinCountPolicy>,
+    #[serde(default)]
+    pub negative_review_count: Vec<MaxCountPolicy>,
+    #[serde(default)]
+    pub review_thoroughness_score: Vec<MinScorePolicy>,
+
+    /// Per-metric multiplier applied to the points a matching policy awards.
+    /// Metrics absent from this map keep a scale factor of `1.0`.
+    #[serde(default)]
+    pub metric_scaling: HashMap<Metric, f64>,
+
+    /// Per-metric weight used when combining continuous sub-scores into the aggregate
+    /// health score (see [`crate::ranking::aggregate_score`]). Metrics absent from this map
+    /// keep a weight of `1.0`.
+    #[serde(default)]
+    pub metric_weights: HashMap<Metric, f64>,
+
+    /// Metrics that should be scored with linear interpolation between adjacent threshold
+    /// policies instead of the default first-match-wins binary path. Only takes effect for
+    /// metrics backed by a [`Policy`] that exposes a [`Policy::breakpoint`]; metrics absent
+    /// from this set keep the binary behavior.
+    #[serde(default)]
+    pub graded_scoring_metrics: HashSet<Metric>,
+
+    /// Stroke color for the download-history sparkline embedded in HTML reports.
+    #[serde(default = "default_sparkline_color")]
+    pub sparkline_color: Color,
+
+    /// Health score threshold below which a crate is considered medium risk (0..100).
+    #[serde(default = "default_medium_risk_threshold")]
+    pub medium_risk_threshold: f64,
+    /// Health score threshold at or above which a crate is considered low risk (0..100).
+    #[serde(default = "default_low_risk_threshold")]
+    pub low_risk_threshold: f64,
+
+    /// Time-boxed exceptions that exempt a matching crate+version from being flagged as
+    /// medium/high risk, documented with a reason and an optional expiry date.
+    #[serde(default)]
+    pub allow_list: Vec<AllowListEntry>,
+    /// How many days before an [`AllowListEntry::expires`] date
+    /// [`Self::allow_list_warnings`] starts calling it out.
+    #[serde(default = "default_allow_list_expiry_warning_days")]
+    pub allow_list_expiry_warning_days: u32,
+
+    /// How much per-request `tracing` detail [`crate::facts::request_tracker::RequestTracker`]
+    /// emits for individual GitHub/docs.rs/codecov.io requests, independent of the aggregate
+    /// progress bar. Defaults to [`RequestLogging::Off`] so interactive runs stay quiet; CI runs
+    /// that want a timing breakdown of the query phase can set this to `completed_only` or `all`.
+    #[serde(default)]
+    pub request_logging: RequestLogging,
+
+    /// Org-specific metrics expressed as sandboxed Rhai scripts instead of built-in policies
+    /// (e.g. "penalize crates whose owner `login` isn't on an allowlist"). Evaluated by
+    /// `crate::ranking::script_engine::ScriptEngine` and aggregated into the synthetic
+    /// [`crate::metrics::MetricCategory::Custom`] category alongside the built-in metrics.
+    #[serde(default)]
+    pub custom_metrics: Vec<ScriptPolicy>,
+
+    /// Duration to keep crates.io database-dump data cached before re-downloading.
+    #[serde(default = "default_cache_ttl", with = "humantime_serde")]
+    pub crates_cache_ttl: Duration,
+    /// Duration to keep hosting (e.g. GitHub) data cached before re-fetching.
+    #[serde(default = "default_cache_ttl", with = "humantime_serde")]
+    pub hosting_cache_ttl: Duration,
+
+    /// Dev/prod mode. In `prod`, [`Self::validate`] additionally enforces the production
+    /// guardrails below, aborting if the configuration is too lenient to run unattended.
+    #[serde(default)]
+    pub profile: Profile,
+    /// In `prod` mode, the minimum allowed value of `medium_risk_threshold`.
+    #[serde(default = "default_prod_minimum_medium_risk_threshold")]
+    pub prod_minimum_medium_risk_threshold: f64,
+    /// In `prod` mode, the minimum allowed value of `low_risk_threshold`.
+    #[serde(default = "default_prod_minimum_low_risk_threshold")]
+    pub prod_minimum_low_risk_threshold: f64,
+    /// In `prod` mode, the maximum allowed value of any `*_cache_ttl` field.
+    #[serde(default = "default_prod_maximum_cache_ttl", with = "humantime_serde")]
+    pub prod_maximum_cache_ttl: Duration,
+
+    /// Overrides the default [`Severity`] that [`Self::resolve_severity`] assigns to a
+    /// [`Diagnostic::code`], keyed by the code string (e.g. `"policy-dominance"`). Lets a
+    /// consumer downgrade a finding to `Info`/`Allow` or escalate it to `Error` without
+    /// changing which `Policy` impl emits it.
+    #[serde(default)]
+    pub diagnostic_severity_overrides: HashMap<String, Severity>,
+
+    /// Where each top-level field's effective value was last set from: an `aprz.yaml` path
+    /// discovered by [`Self::load`], or an `APRZ_*` environment variable name. Not part of
+    /// the document schema; populated after parsing so [`Self::validate`] can name the
+    /// source of a rejected value. Empty for configurations built directly from
+    /// [`Self::from_yaml`] without going through [`Self::load`].
+    #[serde(skip)]
+    field_origins: HashMap<String, String>,
+}
+
+impl Config {
+    /// Parse a configuration document, dispatching on its declared schema `version`, then
+    /// layer `APRZ_`-prefixed environment variable overrides on top (see
+    /// [`Self::apply_env_overrides`]) and validate the result (see [`Self::validate`]).
+    ///
+    /// Documents written before schema versioning was introduced have no `version`
+    /// field and are treated as version `1`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the YAML cannot be parsed, if it declares a schema version
+    /// newer than this build knows how to read, if an environment variable override
+    /// can't be parsed, or if the resulting configuration fails validation.
+    pub fn from_yaml(yaml: &str) -> anyhow::Result<Self> {
+        Self::from_yaml_with_origins(yaml, HashMap::new())
+    }
+
+    /// Same as [`Self::from_yaml`], but seeds [`Self::field_origins`] with `origins` (a
+    /// top-level field name to source description map) before validating, so
+    /// [`Self::validate`] can name where each rejected value came from.
+    ///
+    /// # Errors
+    ///
+    /// See [`Self::from_yaml`].
+    fn from_yaml_with_origins(yaml: &str, origins: HashMap<String, String>) -> anyhow::Result<Self> {
+        #[derive(Deserialize)]
+        struct VersionProbe {
+            #[serde(default = "default_version")]
+            version: u32,
+        }
+
+        let probe: VersionProbe = serde_yaml::from_str(yaml)?;
+        if probe.version > CURRENT_VERSION {
+            anyhow::bail!(
+                "configuration declares schema version {}, but this build only understands up to version {CURRENT_VERSION}; upgrade cargo-rank to read it",
+                probe.version,
+            );
+        }
+
+        // All versions up to and including `CURRENT_VERSION` currently share the same
+        // in-memory shape, so a direct deserialize is sufficient. When the policy
+        // grammar changes, add a migration arm here that rewrites older documents into
+        // the current shape before deserializing them into `Self`.
+        let mut config: Self = serde_yaml::from_str(yaml)?;
+        config.field_origins = origins;
+        config.apply_env_overrides()?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Discover every `aprz.yaml` that applies to `workspace_root` — one in the user's config
+    /// directory, plus one in each ancestor directory from the filesystem root down to
+    /// `workspace_root` itself — and merge them before parsing, mirroring how cargo layers
+    /// `.cargo/config.toml` files found while walking up from the current directory.
+    ///
+    /// Documents are merged farthest-to-nearest so that, for a given key, the file closest to
+    /// `workspace_root` wins: scalar fields (thresholds, TTLs, ...) are simply overwritten,
+    /// while list/map fields (policy vectors, `trusted_review_roots`, `metric_scaling`, ...)
+    /// are merged so that closer files *add to* rather than *replace* farther ones. A missing
+    /// file at any level is skipped; an unparseable one is an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any discovered file can't be parsed as YAML, or if the final
+    /// merged configuration fails [`Self::from_yaml`]'s parsing, environment-override, or
+    /// validation steps.
+    pub fn load(workspace_root: &Path) -> anyhow::Result<Self> {
+        let mut merged = Value::Mapping(serde_yaml::Mapping::new());
+        let mut origins = HashMap::new();
+
+        for path in Self::discover_config_paths(workspace_root) {
+            let Ok(text) = std::fs::read_to_string(&path) else {
+                continue;
+            };
+
+            let doc: Value = serde_yaml::from_str(&text).map_err(|e| anyhow::anyhow!("parsing configuration file '{}': {e}", path.display()))?;
+            if let Value::Mapping(table) = &doc {
+                for key in table.keys().filter_map(Value::as_str) {
+                    _ = origins.insert(key.to_string(), path.display().to_string());
+                }
+            }
+            merged = merge_yaml(merged, doc);
+        }
+
+        let yaml = serde_yaml::to_string(&merged)?;
+        Self::from_yaml_with_origins(&yaml, origins)
+    }
+
+    /// Candidate `aprz.yaml` locations for `workspace_root`, ordered from lowest to highest
+    /// merge precedence: the user's config directory first, then each ancestor directory from
+    /// the filesystem root down to `workspace_root` itself.
+    fn discover_config_paths(workspace_root: &Path) -> Vec<PathBuf> {
+        let mut paths = Vec::new();
+
+        if let Some(config_dir) = dirs::config_dir() {
+            paths.push(config_dir.join("aprz.yaml"));
+        }
+
+        let mut ancestors: Vec<_> = workspace_root.ancestors().map(Path::to_path_buf).collect();
+        ancestors.reverse();
+        paths.extend(ancestors.into_iter().map(|dir| dir.join("aprz.yaml")));
+
+        paths
+    }
+
+    /// Layer `APRZ_`-prefixed environment variables over the parsed configuration, mirroring
+    /// how cargo lets environment variables shadow `.cargo/config.toml` values. Each variable
+    /// replaces exactly one field, uppercased with dashes turned into underscores:
+    ///
+    /// - `APRZ_MEDIUM_RISK_THRESHOLD`, `APRZ_LOW_RISK_THRESHOLD`: parsed as `f64`.
+    /// - `APRZ_CRATES_CACHE_TTL`, `APRZ_HOSTING_CACHE_TTL`: parsed with the same
+    ///   [`humantime`] format as their `humantime_serde`-annotated YAML counterparts
+    ///   (e.g. `"7days"`, `"12h"`).
+    ///
+    /// Runs before [`Self::validate`], so an out-of-range override is rejected through the
+    /// same error path as an out-of-range file value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a set environment variable can't be parsed as the field's type.
+    fn apply_env_overrides(&mut self) -> anyhow::Result<()> {
+        if let Ok(value) = std::env::var("APRZ_MEDIUM_RISK_THRESHOLD") {
+            self.medium_risk_threshold = value
+                .parse()
+                .map_err(|e| anyhow::anyhow!("APRZ_MEDIUM_RISK_THRESHOLD={value:?} is not a valid number: {e}"))?;
+            _ = self.field_origins.insert("medium_risk_threshold".to_string(), "environment variable APRZ_MEDIUM_RISK_THRESHOLD".to_string());
+        }
+
+        if let Ok(value) = std::env::var("APRZ_LOW_RISK_THRESHOLD") {
+            self.low_risk_threshold = value
+                .parse()
+                .map_err(|e| anyhow::anyhow!("APRZ_LOW_RISK_THRESHOLD={value:?} is not a valid number: {e}"))?;
+            _ = self.field_origins.insert("low_risk_threshold".to_string(), "environment variable APRZ_LOW_RISK_THRESHOLD".to_string());
+        }
+
+        if let Ok(value) = std::env::var("APRZ_CRATES_CACHE_TTL") {
+            self.crates_cache_ttl =
+                humantime::parse_duration(&value).map_err(|e| anyhow::anyhow!("APRZ_CRATES_CACHE_TTL={value:?} is not a valid duration: {e}"))?;
+            _ = self.field_origins.insert("crates_cache_ttl".to_string(), "environment variable APRZ_CRATES_CACHE_TTL".to_string());
+        }
+
+        if let Ok(value) = std::env::var("APRZ_HOSTING_CACHE_TTL") {
+            self.hosting_cache_ttl = humantime::parse_duration(&value)
+                .map_err(|e| anyhow::anyhow!("APRZ_HOSTING_CACHE_TTL={value:?} is not a valid duration: {e}"))?;
+            _ = self.field_origins.insert("hosting_cache_ttl".to_string(), "environment variable APRZ_HOSTING_CACHE_TTL".to_string());
+        }
+
+        Ok(())
+    }
+
+    /// Describe where `field`'s effective value came from, for use in [`Self::validate`]
+    /// error messages. Returns an empty string when the origin isn't known (e.g. the
+    /// configuration was built with [`Self::from_yaml`] directly, or the value is a
+    /// built-in default that was never overridden).
+    fn origin_suffix(&self, field: &str) -> String {
+        self.field_origins.get(field).map_or(String::new(), |origin| format!(" in `{origin}`"))
+    }
+
+    /// Validate cross-field invariants that the type system alone can't express, plus —
+    /// when [`Self::profile`] is [`Profile::Prod`] — the production guardrails documented on
+    /// [`Self::prod_minimum_medium_risk_threshold`], [`Self::prod_minimum_low_risk_threshold`],
+    /// and [`Self::prod_maximum_cache_ttl`], a non-empty [`Self::custom_metrics`], and a
+    /// [`Self::allow_list`] whose entries all carry a [`AllowListEntry::reason`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a single error aggregating every violation found, so a misconfigured team
+    /// sees the complete list in one pass instead of fixing and re-running one field at a
+    /// time.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        let mut errors = Vec::new();
+
+        if !(0.0..=100.0).contains(&self.medium_risk_threshold) {
+            errors.push(format!(
+                "`medium_risk_threshold = {}`{} must be between 0 and 100",
+                self.medium_risk_threshold,
+                self.origin_suffix("medium_risk_threshold"),
+            ));
+        }
+
+        if !(0.0..=100.0).contains(&self.low_risk_threshold) {
+            errors.push(format!(
+                "`low_risk_threshold = {}`{} must be between 0 and 100",
+                self.low_risk_threshold,
+                self.origin_suffix("low_risk_threshold"),
+            ));
+        }
+
+        if self.medium_risk_threshold >= self.low_risk_threshold {
+            errors.push(format!(
+                "`medium_risk_threshold = {}`{} must be less than `low_risk_threshold = {}`{}",
+                self.medium_risk_threshold,
+                self.origin_suffix("medium_risk_threshold"),
+                self.low_risk_threshold,
+                self.origin_suffix("low_risk_threshold"),
+            ));
+        }
+
+        if self.profile == Profile::Prod {
+            if self.medium_risk_threshold < self.prod_minimum_medium_risk_threshold {
+                errors.push(format!(
+                    "prod profile requires medium_risk_threshold >= {}, but `medium_risk_threshold = {}`{}",
+                    self.prod_minimum_medium_risk_threshold,
+                    self.medium_risk_threshold,
+                    self.origin_suffix("medium_risk_threshold"),
+                ));
+            }
+
+            if self.low_risk_threshold < self.prod_minimum_low_risk_threshold {
+                errors.push(format!(
+                    "prod profile requires low_risk_threshold >= {}, but `low_risk_threshold = {}`{}",
+                    self.prod_minimum_low_risk_threshold,
+                    self.low_risk_threshold,
+                    self.origin_suffix("low_risk_threshold"),
+                ));
+            }
+
+            if self.crates_cache_ttl > self.prod_maximum_cache_ttl {
+                errors.push(format!(
+                    "prod profile requires crates_cache_ttl <= {:?}, but `crates_cache_ttl = {:?}`{}",
+                    self.prod_maximum_cache_ttl,
+                    self.crates_cache_ttl,
+                    self.origin_suffix("crates_cache_ttl"),
+                ));
+            }
+
+            if self.hosting_cache_ttl > self.prod_maximum_cache_ttl {
+                errors.push(format!(
+                    "prod profile requires hosting_cache_ttl <= {:?}, but `hosting_cache_ttl = {:?}`{}",
+                    self.prod_maximum_cache_ttl,
+                    self.hosting_cache_ttl,
+                    self.origin_suffix("hosting_cache_ttl"),
+                ));
+            }
+
+            // The request this guardrail came from also called for a non-empty `eval`/
+            // `high_risk` expression list, but this config has no `high_risk` field, and
+            // `custom_metrics` (the `ScriptPolicy` list `eval` scripts actually live in) is the
+            // closest real equivalent, so that's what's enforced here instead.
+            if self.custom_metrics.is_empty() {
+                errors.push(format!(
+                    "prod profile requires at least one custom_metrics script, but none are configured{}",
+                    self.origin_suffix("custom_metrics"),
+                ));
+            }
+
+            for entry in &self.allow_list {
+                if entry.reason.as_deref().is_none_or(str::is_empty) {
+                    errors.push(format!("prod profile requires a reason on every allow_list entry, but '{}' has none", entry.name));
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            anyhow::bail!("configuration failed validation:\n  - {}", errors.join("\n  - "));
+        }
+    }
+
+    /// Check if a crate+version is covered by a current (non-expired) [`AllowListEntry`].
+    #[must_use]
+    pub fn is_allowed(&self, name: &str, version: &Version) -> bool {
+        let today = Utc::now().date_naive();
+        self.allow_list.iter().any(|entry| entry.matches(name, version, today))
+    }
+
+    /// Classify `health_score` against [`Self::medium_risk_threshold`]/
+    /// [`Self::low_risk_threshold`], honoring a current [`Self::allow_list`] exemption for
+    /// `name`/`version` over what would otherwise be a `Medium`/`High` classification.
+    #[must_use]
+    pub fn classify_risk(&self, name: &str, version: &Version, health_score: f64) -> RiskLevel {
+        if health_score >= self.low_risk_threshold {
+            RiskLevel::Low
+        } else if self.is_allowed(name, version) {
+            RiskLevel::Exempt
+        } else if health_score < self.medium_risk_threshold {
+            RiskLevel::High
+        } else {
+            RiskLevel::Medium
+        }
+    }
+
+    /// Non-fatal warnings about [`Self::allow_list`] entries that deserve a reviewer's
+    /// attention: ones that have already expired (and so silently stopped applying) and ones
+    /// that will expire within [`Self::allow_list_expiry_warning_days`]. Unlike
+    /// [`Self::validate`], these never fail configuration loading — an expiring waiver is a
+    /// cleanup reminder, not a misconfiguration.
+    #[must_use]
+    pub fn allow_list_warnings(&self) -> Vec<String> {
+        let today = Utc::now().date_naive();
+        let mut warnings = Vec::new();
+
+        for entry in &self.allow_list {
+            if entry.is_expired(today) {
+                warnings.push(format!(
+                    "allow_list entry '{}' expired on {} and no longer exempts matching crates",
+                    entry.name,
+                    entry.expires.expect("is_expired only returns true when expires is set"),
+                ));
+            } else if entry.expires_soon(today, self.allow_list_expiry_warning_days) {
+                warnings.push(format!(
+                    "allow_list entry '{}' expires on {}, within the {}-day warning window",
+                    entry.name,
+                    entry.expires.expect("expires_soon only returns true when expires is set"),
+                    self.allow_list_expiry_warning_days,
+                ));
+            }
+        }
+
+        warnings
+    }
+
+    /// Resolve the effective [`Severity`] for `diagnostic`: an entry in
+    /// [`Self::diagnostic_severity_overrides`] keyed by [`Diagnostic::code`] if one exists,
+    /// otherwise the severity the emitting `Policy` impl assigned it.
+    #[must_use]
+    pub fn resolve_severity(&self, diagnostic: &Diagnostic) -> Severity {
+        self.diagnostic_severity_overrides.get(diagnostic.code).copied().unwrap_or(diagnostic.severity)
+    }
+
+    /// Run [`Policy::validate`] across every configured policy list and collect the results,
+    /// each message prefixed with the [`Metric`] it came from. Severities are the defaults the
+    /// emitting impls assigned; [`Self::diagnostic_severity_overrides`] is not applied here (use
+    /// [`Self::resolve_severity`] for that).
+    #[must_use]
+    pub fn policy_diagnostics(&self) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+
+        diagnostics.extend(LicensePolicy::validate(Metric::License, &self.license));
+        diagnostics.extend(AgePolicy::validate(Metric::Age, &self.age));
+        diagnostics.extend(VersionPolicy::validate(Metric::MinVersion, &self.min_version));
+        diagnostics.extend(MsrvPolicy::validate(Metric::Msrv, &self.msrv));
+        diagnostics.extend(AgedCountPolicy::validate(Metric::ReleaseCount, &self.release_count));
+        diagnostics.extend(AuditCoveragePolicy::validate(Metric::AuditCoverage, &self.audit_coverage));
+        diagnostics.extend(PercentagePolicy::validate(Metric::DependencyFreshness, &self.dependency_freshness));
+        diagnostics.extend(MaintenanceStatusPolicy::validate(Metric::MaintenanceStatus, &self.maintenance_status));
+
+        diagnostics.extend(MinCountPolicy::validate(Metric::OverallDownloadCount, &self.overall_download_count));
+        diagnostics.extend(MinCountPolicy::validate(Metric::OneMonthDownloadCount, &self.one_month_download_count));
+        diagnostics.extend(MinCountPolicy::validate(Metric::AdjustedMonthlyDownloads, &self.adjusted_monthly_downloads));
+        diagnostics.extend(MinScorePolicy::validate(Metric::DownloadTrend, &self.download_trend));
+
+        diagnostics.extend(MinCountPolicy::validate(Metric::OverallOwnerCount, &self.overall_owner_count));
+        diagnostics.extend(MinCountPolicy::validate(Metric::TeamOwnerCount, &self.team_owner_count));
+        diagnostics.extend(MinCountPolicy::validate(Metric::UserOwnerCount, &self.user_owner_count));
+
+        diagnostics.extend(MaxCountPolicy::validate(Metric::DirectDependencyCount, &self.direct_dependency_count));
+        diagnostics.extend(MinCountPolicy::validate(Metric::DependentCount, &self.dependent_count));
+        diagnostics.extend(ReverseDepsPolicy::validate(
+            Metric::RequiredReverseDependencyCount,
+            &self.required_reverse_dependency_count,
+        ));
+
+        diagnostics.extend(MaxCountPolicy::validate(Metric::TarballSize, &self.tarball_size));
+        diagnostics.extend(MaxCountPolicy::validate(Metric::UncompressedSize, &self.uncompressed_size));
+        diagnostics.extend(MaxCountPolicy::validate(Metric::DependencyWeight, &self.dependency_weight));
+        diagnostics.extend(MaxCountPolicy::validate(Metric::InstalledWithDepsSize, &self.installed_with_deps_size));
+        diagnostics.extend(MaxCountPolicy::validate(Metric::MinimalDependencyFootprint, &self.minimal_dependency_footprint));
+        diagnostics.extend(MaxCountPolicy::validate(Metric::LinesOfCode, &self.lines_of_code));
+
+        diagnostics.extend(PercentagePolicy::validate(Metric::DocCoveragePercentage, &self.doc_coverage_percentage));
+        diagnostics.extend(MaxCountPolicy::validate(Metric::BrokenDocLinkCount, &self.broken_doc_link_count));
+        diagnostics.extend(PercentagePolicy::validate(Metric::CodeCoveragePercentage, &self.code_coverage_percentage));
+        diagnostics.extend(BooleanPolicy::validate(Metric::FullySafeCode, &self.fully_safe_code));
+        diagnostics.extend(MaxCountPolicy::validate(Metric::NonRustLanguageLineCount, &self.non_rust_language_line_count));
+        diagnostics.extend(MaxCountPolicy::validate(Metric::TransitiveDependencyCount, &self.transitive_dependency_count));
+        diagnostics.extend(MinCountPolicy::validate(Metric::ExampleCount, &self.example_count));
+        diagnostics.extend(PercentagePolicy::validate(Metric::CommentRatio, &self.comment_ratio));
+
+        diagnostics.extend(MinCountPolicy::validate(Metric::RepoContributorCount, &self.repo_contributor_count));
+        diagnostics.extend(MinCountPolicy::validate(Metric::RepoStarCount, &self.repo_star_count));
+        diagnostics.extend(MinCountPolicy::validate(Metric::RepoForkCount, &self.repo_fork_count));
+        diagnostics.extend(MinCountPolicy::validate(Metric::RepoSubscriberCount, &self.repo_subscriber_count));
+        diagnostics.extend(AgedCountPolicy::validate(Metric::CommitActivity, &self.commit_activity));
+
+        diagnostics.extend(MaxCountPolicy::validate(Metric::OpenIssueCount, &self.open_issue_count));
+        diagnostics.extend(MinCountPolicy::validate(Metric::ClosedIssueCount, &self.closed_issue_count));
+        diagnostics.extend(ResponsivenessPolicy::validate(Metric::IssueResponsiveness, &self.issue_responsiveness));
+        diagnostics.extend(MaxCountPolicy::validate(Metric::OpenPullRequestCount, &self.open_pull_request_count));
+        diagnostics.extend(MinCountPolicy::validate(Metric::ClosedPullRequestCount, &self.closed_pull_request_count));
+        diagnostics.extend(ResponsivenessPolicy::validate(
+            Metric::PullRequestResponsiveness,
+            &self.pull_request_responsiveness,
+        ));
+
+        diagnostics.extend(MaxCountPolicy::validate(Metric::VulnerabilityCount, &self.vulnerability_count));
+        diagnostics.extend(MaxCountPolicy::validate(Metric::LowVulnerabilityCount, &self.low_vulnerability_count));
+        diagnostics.extend(MaxCountPolicy::validate(Metric::MediumVulnerabilityCount, &self.medium_vulnerability_count));
+        diagnostics.extend(MaxCountPolicy::validate(Metric::HighVulnerabilityCount, &self.high_vulnerability_count));
+        diagnostics.extend(MaxCountPolicy::validate(Metric::CriticalVulnerabilityCount, &self.critical_vulnerability_count));
+        diagnostics.extend(MaxScorePolicy::validate(
+            Metric::CvssWeightedVulnerabilityScore,
+            &self.cvss_weighted_vulnerability_score,
+        ));
+        diagnostics.extend(MaxCountPolicy::validate(Metric::WarningCount, &self.warning_count));
+        diagnostics.extend(MaxCountPolicy::validate(Metric::NoticeWarningCount, &self.notice_warning_count));
+        diagnostics.extend(MaxCountPolicy::validate(Metric::UnmaintainedWarningCount, &self.unmaintained_warning_count));
+        diagnostics.extend(MaxCountPolicy::validate(Metric::UnsoundWarningCount, &self.unsound_warning_count));
+        diagnostics.extend(MaxCountPolicy::validate(Metric::YankedWarningCount, &self.yanked_warning_count));
+
+        diagnostics.extend(MaxCountPolicy::validate(
+            Metric::HistoricalVulnerabilityCount,
+            &self.historical_vulnerability_count,
+        ));
+        diagnostics.extend(MaxCountPolicy::validate(
+            Metric::HistoricalLowVulnerabilityCount,
+            &self.historical_low_vulnerability_count,
+        ));
+        diagnostics.extend(MaxCountPolicy::validate(
+            Metric::HistoricalMediumVulnerabilityCount,
+            &self.historical_medium_vulnerability_count,
+        ));
+        diagnostics.extend(MaxCountPolicy::validate(
+            Metric::HistoricalHighVulnerabilityCount,
+            &self.historical_high_vulnerability_count,
+        ));
+        diagnostics.extend(MaxCountPolicy::validate(
+            Metric::HistoricalCriticalVulnerabilityCount,
+            &self.historical_critical_vulnerability_count,
+        ));
+        diagnostics.extend(MaxScorePolicy::validate(
+            Metric::HistoricalCvssWeightedVulnerabilityScore,
+            &self.historical_cvss_weighted_vulnerability_score,
+        ));
+        diagnostics.extend(MaxCountPolicy::validate(Metric::HistoricalWarningCount, &self.historical_warning_count));
+        diagnostics.extend(MaxCountPolicy::validate(
+            Metric::HistoricalNoticeWarningCount,
+            &self.historical_notice_warning_count,
+        ));
+        diagnostics.extend(MaxCountPolicy::validate(
+            Metric::HistoricalUnmaintainedWarningCount,
+            &self.historical_unmaintained_warning_count,
+        ));
+        diagnostics.extend(MaxCountPolicy::validate(
+            Metric::HistoricalUnsoundWarningCount,
+            &self.historical_unsound_warning_count,
+        ));
+        diagnostics.extend(MaxCountPolicy::validate(
+            Metric::HistoricalYankedWarningCount,
+            &self.historical_yanked_warning_count,
+        ));
+        diagnostics.extend(ResponsivenessPolicy::validate(
+            Metric::AdvisoryPatchResponsiveness,
+            &self.advisory_patch_responsiveness,
+        ));
+
+        diagnostics.extend(MinCountPolicy::validate(Metric::TrustedReviewCount, &self.trusted_review_count));
+        diagnostics.extend(MaxCountPolicy::validate(Metric::NegativeReviewCount, &self.negative_review_count));
+        diagnostics.extend(MinScorePolicy::validate(Metric::ReviewThoroughnessScore, &self.review_thoroughness_score));
+
+        diagnostics.extend(ScriptPolicy::validate(&self.custom_metrics));
+
+        diagnostics
+    }
+
+    /// Whether [`Self::policy_diagnostics`], after applying [`Self::diagnostic_severity_overrides`]
+    /// via [`Self::resolve_severity`], contains any [`Severity::Error`]-level finding. Mirrors
+    /// what a `validate` entry point should use to decide its process exit code.
+    #[must_use]
+    pub fn has_blocking_diagnostics(&self) -> bool {
+        self.policy_diagnostics()
+            .iter()
+            .any(|diagnostic| self.resolve_severity(diagnostic) == Severity::Error)
+    }
+}
+
+/// Recursively merges `overlay` onto `base`: mappings are merged key-by-key (recursing into
+/// values present in both), sequences are concatenated (`base` then `overlay`), and anything
+/// else is simply replaced by `overlay`'s value. Used by [`Config::load`] to layer
+/// `aprz.yaml` files found at multiple directory levels.
+fn merge_yaml(base: Value, overlay: Value) -> Value {
+    match (base, overlay) {
+        (Value::Mapping(mut base_map), Value::Mapping(overlay_map)) => {
+            for (key, overlay_value) in overlay_map {
+                let merged = match base_map.remove(&key) {
+                    Some(base_value) => merge_yaml(base_value, overlay_value),
+                    None => overlay_value,
+                };
+                _ = base_map.insert(key, merged);
+            }
+            Value::Mapping(base_map)
+        }
+        (Value::Sequence(mut base_seq), Value::Sequence(overlay_seq)) => {
+            base_seq.extend(overlay_seq);
+            Value::Sequence(base_seq)
+        }
+        (_, overlay) => overlay,
+    }
+}
@@ -0,0 +1,47 @@
+//! Structured findings shared by every [`crate::config::Policy`] implementation.
+
+use serde::{Deserialize, Serialize};
+
+/// How seriously a [`Diagnostic`] should be treated. A code's default severity (chosen by the
+/// `Policy` impl that emits it) can be remapped by
+/// [`Config::diagnostic_severity_overrides`](crate::config::Config::diagnostic_severity_overrides),
+/// e.g. to escalate "policy dominance" to `Error` in CI, or silence it with `Allow`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    /// Should fail validation outright.
+    Error,
+    /// Worth a reviewer's attention, but not fatal.
+    Warning,
+    /// Informational; rarely worth surfacing by default.
+    Info,
+    /// Suppressed entirely.
+    Allow,
+}
+
+/// A single finding from [`Policy::validate`](crate::config::Policy::validate): a stable
+/// `code` for filtering/machine consumption (e.g. a future JSON output mode), the
+/// human-readable `message`, and the indices (within their config list) of the policies it's
+/// about.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    /// Stable identifier for the kind of finding, independent of wording (e.g. `"policy-dominance"`).
+    pub code: &'static str,
+
+    /// Default severity for this finding; see [`Config::resolve_severity`](crate::config::Config::resolve_severity)
+    /// for how a user can override it per-code.
+    pub severity: Severity,
+
+    /// Human-readable description of the finding.
+    pub message: String,
+
+    /// Indices, within the metric's configured policy list, of the policies this finding is about.
+    pub policy_indices: Vec<usize>,
+}
+
+impl Diagnostic {
+    #[must_use]
+    pub fn new(code: &'static str, severity: Severity, message: String, policy_indices: Vec<usize>) -> Self {
+        Self { code, severity, message, policy_indices }
+    }
+}
@@ -1,13 +1,18 @@
+mod allow_list_entry;
 mod color;
 #[expect(clippy::module_inception, reason = "I like it this way")]
 mod config;
+mod diagnostic;
 mod policies;
 mod policy;
 
+pub use allow_list_entry::AllowListEntry;
 pub use color::Color;
 pub use config::{Config, DEFAULT_CONFIG_YAML};
+pub use diagnostic::{Diagnostic, Severity};
 pub use policies::{
-    AgePolicy, AgedCountPolicy, BooleanPolicy, LicensePolicy, MaxCountPolicy, MinCountPolicy, PercentagePolicy, ResponsivenessPolicy,
-    VersionPolicy,
+    AgePolicy, AgedCountPolicy, AuditCoveragePolicy, BooleanPolicy, LicensePolicy, MaintenanceStatusPolicy, MaxCountPolicy,
+    MaxScorePolicy, MinCountPolicy, MinScorePolicy, MsrvPolicy, PercentagePolicy, ResponsivenessPolicy, ReverseDepsPolicy,
+    SCRIPT_NAME_DUPLICATE, ScriptPolicy, VersionPolicy,
 };
-pub use policy::Policy;
+pub use policy::{POLICY_DOMINANCE, POLICY_DUPLICATE, Policy};
@@ -0,0 +1,54 @@
+use crate::config::diagnostic::{Diagnostic, Severity};
+use crate::config::policy::{POLICY_DUPLICATE, Policy};
+use crate::metrics::Metric;
+use crate::misc::DependencyTypes;
+use serde::{Deserialize, Serialize};
+
+/// Required cargo-vet criteria a crate version must be audited for.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct AuditCoveragePolicy {
+    #[serde(default)]
+    pub dependency_types: DependencyTypes,
+
+    /// Criteria the audit graph must provide a certified path to, e.g. `["safe-to-deploy"]`.
+    /// Composes with [`crate::facts::vet::CriteriaGraph`]'s implication closure, so requiring
+    /// `safe-to-run` is also met by an audit that only names `safe-to-deploy`.
+    pub required_criteria: Vec<String>,
+
+    pub points: f64,
+}
+
+impl Policy for AuditCoveragePolicy {
+    fn dependency_types(&self) -> &DependencyTypes {
+        &self.dependency_types
+    }
+
+    fn points(&self) -> f64 {
+        self.points
+    }
+
+    fn validate<'a>(metric: Metric, policies: impl IntoIterator<Item = &'a Self>) -> Vec<Diagnostic>
+    where
+        Self: 'a,
+    {
+        let mut diagnostics = Vec::new();
+        let policies: Vec<_> = policies.into_iter().collect();
+
+        for (i, policy_a) in policies.iter().enumerate() {
+            for (j, policy_b) in policies.iter().enumerate().skip(i + 1) {
+                let overlap = policy_a.dependency_types().intersect(policy_b.dependency_types());
+                if !overlap.is_empty() {
+                    diagnostics.push(Diagnostic::new(
+                        POLICY_DUPLICATE,
+                        Severity::Warning,
+                        format!("{metric}: Policy #{i} duplicates policy #{j} for dependency types '{overlap}'"),
+                        vec![i, j],
+                    ));
+                }
+            }
+        }
+
+        diagnostics
+    }
+}
@@ -1,4 +1,5 @@
-use crate::config::policy::Policy;
+use crate::config::diagnostic::{Diagnostic, Severity};
+use crate::config::policy::{POLICY_DOMINANCE, Policy};
 use crate::metrics::Metric;
 use crate::misc::DependencyTypes;
 use core::fmt::Formatter;
@@ -16,6 +17,13 @@ pub struct LicensePolicy {
     #[serde(serialize_with = "serialize_licenses", deserialize_with = "deserialize_licenses")]
     pub licenses: HashSet<String>,
 
+    /// When `true`, a `WITH`-exception license (e.g. `GPL-2.0-only WITH Classpath-exception-2.0`)
+    /// is accepted regardless of whether the exception name itself is in `licenses`, as long as
+    /// the base license is. Defaults to `false`, which requires the exception name to also be
+    /// listed in `licenses`.
+    #[serde(default)]
+    pub allow_any_exception: bool,
+
     pub points: f64,
 }
 
@@ -65,7 +73,7 @@ impl LicensePolicy {
     pub fn check_license(&self, spdx_license_expr: &str) -> bool {
         // Try to parse as SPDX expression
         let Ok(expression) = spdx::Expression::parse(spdx_license_expr) else {
-            // If parsing fails, fall back to simple substring matching for backward compatibility
+            // If parsing truly fails, fall back to simple substring matching for backward compatibility
             let license_lower = spdx_license_expr.to_lowercase();
             return self.licenses.iter().any(|allowed| {
                 let allowed_lower = allowed.to_lowercase();
@@ -73,64 +81,54 @@ impl LicensePolicy {
             });
         };
 
-        // Manually walk the expression tree to properly evaluate AND/OR logic
-        // We need to collect all requirements and evaluate the expression structure
-        Self::evaluate_spdx_expression(&expression, &self.licenses)
+        self.evaluate_spdx_expression(&expression)
     }
 
-    /// Recursively evaluate an SPDX expression
-    fn evaluate_spdx_expression(expr: &spdx::Expression, allowed_licenses: &HashSet<String>) -> bool {
-        // Iterate through the expression nodes
-        // The expression provides an iterator, but we need to manually track the structure
-        // For now, let's use a simpler approach: check if all requirements are satisfied
-
-        // Get all license requirements
-        let all_requirements: Vec<_> = expr.requirements().collect();
-
-        if all_requirements.is_empty() {
+    /// Evaluate a parsed SPDX expression against `self.licenses`, letting [`spdx::Expression`]
+    /// apply the AND/OR/precedence/parenthesis logic of the expression tree itself (e.g.
+    /// `(MIT OR Apache-2.0) AND Unicode-DFS-2016`) rather than re-deriving it by inspecting
+    /// the formatted expression string.
+    fn evaluate_spdx_expression(&self, expr: &spdx::Expression) -> bool {
+        // An expression with no requirements at all (shouldn't occur for anything `parse`
+        // accepted, but guard against it rather than treating it as vacuously satisfied).
+        if expr.requirements().next().is_none() {
             return false;
         }
 
-        // For each requirement, check if it's allowed
-        let mut has_allowed = false;
-        let mut has_disallowed = false;
-
-        for req in &all_requirements {
-            let Some(license_id_obj) = req.req.license.id() else {
-                has_disallowed = true;
-                continue;
-            };
-
-            let license_id = license_id_obj.name.to_lowercase();
-
-            let is_allowed = allowed_licenses.iter().any(|allowed| {
-                let allowed_lower = allowed.to_lowercase();
-                license_id.contains(&allowed_lower) || allowed_lower.contains(&license_id)
-            });
+        expr.evaluate(|req| {
+            if !self.is_license_allowed(&req.license) {
+                return false;
+            }
 
-            if is_allowed {
-                has_allowed = true;
-            } else {
-                has_disallowed = true;
+            match &req.exception {
+                Some(exception) => self.allow_any_exception || self.is_name_allowed(exception.name),
+                None => true,
             }
-        }
+        })
+    }
 
-        // Check if the expression contains AND or OR operators
-        // by parsing the original string (not ideal, but the spdx crate doesn't expose the tree structure easily)
-        let expr_str = format!("{expr}");
-        let has_and = expr_str.contains(" AND ");
-
-        // Apply logic based on operators:
-        // - If there's an AND, ALL licenses must be allowed
-        // - If there's only OR (or neither), at least ONE license must be allowed
-        if has_and {
-            // For AND: all requirements must be satisfied
-            !has_disallowed && has_allowed
-        } else {
-            // For OR or single license: at least one requirement must be satisfied
-            has_allowed
+    /// Resolve `license`'s SPDX id (with `+`/or-later already stripped to its base id by the
+    /// `spdx` crate) or, for a custom `LicenseRef-*` name, its full reference name, and check
+    /// it against the allowed set.
+    fn is_license_allowed(&self, license: &spdx::LicenseItem) -> bool {
+        match license.id() {
+            Some(id) => self.is_name_allowed(id.name),
+            None => match license {
+                spdx::LicenseItem::Other { lic_ref, .. } => self.is_name_allowed(&format!("LicenseRef-{lic_ref}")),
+                spdx::LicenseItem::Spdx { .. } => false,
+            },
         }
     }
+
+    /// Fuzzy (substring, case-insensitive) match against the allowed license/exception names,
+    /// matching the tolerance of the unparseable-expression fallback in [`Self::check_license`].
+    fn is_name_allowed(&self, name: &str) -> bool {
+        let name_lower = name.to_lowercase();
+        self.licenses.iter().any(|allowed| {
+            let allowed_lower = allowed.to_lowercase();
+            name_lower.contains(&allowed_lower) || allowed_lower.contains(&name_lower)
+        })
+    }
 }
 
 impl Policy for LicensePolicy {
@@ -142,11 +140,11 @@ impl Policy for LicensePolicy {
         self.points
     }
 
-    fn validate<'a>(metric: Metric, policies: impl IntoIterator<Item = &'a Self>) -> Vec<String>
+    fn validate<'a>(metric: Metric, policies: impl IntoIterator<Item = &'a Self>) -> Vec<Diagnostic>
     where
         Self: 'a,
     {
-        let mut warnings = Vec::new();
+        let mut diagnostics = Vec::new();
         let policies: Vec<_> = policies.into_iter().collect();
 
         for (i, policy_a) in policies.iter().enumerate() {
@@ -161,14 +159,19 @@ impl Policy for LicensePolicy {
                 let mut intersection: Vec<String> = policy_a.licenses.intersection(&policy_b.licenses).cloned().collect();
                 if !intersection.is_empty() {
                     intersection.sort_unstable();
-                    warnings.push(format!(
-                        "{metric}: Policies at index {i} dominates policy at index {j} for dependency types '{overlap}' and licenses '{}'",
-                        intersection.join(", ")
+                    diagnostics.push(Diagnostic::new(
+                        POLICY_DOMINANCE,
+                        Severity::Warning,
+                        format!(
+                            "{metric}: Policies at index {i} dominates policy at index {j} for dependency types '{overlap}' and licenses '{}'",
+                            intersection.join(", ")
+                        ),
+                        vec![i, j],
                     ));
                 }
             }
         }
 
-        warnings
+        diagnostics
     }
 }
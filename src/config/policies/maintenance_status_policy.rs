@@ -0,0 +1,53 @@
+use crate::config::diagnostic::{Diagnostic, Severity};
+use crate::config::policy::{POLICY_DUPLICATE, Policy};
+use crate::facts::crates::MaintenanceStatus;
+use crate::metrics::Metric;
+use crate::misc::DependencyTypes;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Maintenance-badge statuses considered acceptable, e.g. `actively-developed`.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct MaintenanceStatusPolicy {
+    #[serde(default)]
+    pub dependency_types: DependencyTypes,
+
+    pub accepted_statuses: HashSet<MaintenanceStatus>,
+
+    pub points: f64,
+}
+
+impl Policy for MaintenanceStatusPolicy {
+    fn dependency_types(&self) -> &DependencyTypes {
+        &self.dependency_types
+    }
+
+    fn points(&self) -> f64 {
+        self.points
+    }
+
+    fn validate<'a>(metric: Metric, policies: impl IntoIterator<Item = &'a Self>) -> Vec<Diagnostic>
+    where
+        Self: 'a,
+    {
+        let mut diagnostics = Vec::new();
+        let policies: Vec<_> = policies.into_iter().collect();
+
+        for (i, policy_a) in policies.iter().enumerate() {
+            for (j, policy_b) in policies.iter().enumerate().skip(i + 1) {
+                let overlap = policy_a.dependency_types().intersect(policy_b.dependency_types());
+                if !overlap.is_empty() {
+                    diagnostics.push(Diagnostic::new(
+                        POLICY_DUPLICATE,
+                        Severity::Warning,
+                        format!("{metric}: Policy #{i} duplicates policy #{j} for dependency types '{overlap}'"),
+                        vec![i, j],
+                    ));
+                }
+            }
+        }
+
+        diagnostics
+    }
+}
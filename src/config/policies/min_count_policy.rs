@@ -1,4 +1,5 @@
-use crate::config::policy::Policy;
+use crate::config::diagnostic::{Diagnostic, Severity};
+use crate::config::policy::{POLICY_DOMINANCE, Policy};
 use crate::metrics::Metric;
 use crate::misc::DependencyTypes;
 use serde::{Deserialize, Serialize};
@@ -23,11 +24,15 @@ impl Policy for MinCountPolicy {
         self.points
     }
 
-    fn validate<'a>(metric: Metric, policies: impl IntoIterator<Item = &'a Self>) -> Vec<String>
+    fn breakpoint(&self) -> Option<f64> {
+        Some(f64::from(self.min_count))
+    }
+
+    fn validate<'a>(metric: Metric, policies: impl IntoIterator<Item = &'a Self>) -> Vec<Diagnostic>
     where
         Self: 'a,
     {
-        let mut warnings = Vec::new();
+        let mut diagnostics = Vec::new();
         let policies: Vec<_> = policies.into_iter().collect();
 
         for (i, policy_a) in policies.iter().enumerate() {
@@ -40,13 +45,16 @@ impl Policy for MinCountPolicy {
                 }
 
                 if policy_a.min_count <= policy_b.min_count {
-                    warnings.push(format!(
-                        "{metric}: Policy #{i} dominates policy #{j} for dependency types '{overlap}'"
+                    diagnostics.push(Diagnostic::new(
+                        POLICY_DOMINANCE,
+                        Severity::Warning,
+                        format!("{metric}: Policy #{i} dominates policy #{j} for dependency types '{overlap}'"),
+                        vec![i, j],
                     ));
                 }
             }
         }
 
-        warnings
+        diagnostics
     }
 }
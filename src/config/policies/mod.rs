@@ -1,19 +1,33 @@
 mod age_policy;
 mod aged_count_policy;
+mod audit_coverage_policy;
 mod boolean_policy;
 mod license_policy;
+mod maintenance_status_policy;
 mod max_count_policy;
+mod max_score_policy;
 mod min_count_policy;
+mod min_score_policy;
+mod msrv_policy;
 mod percentage_policy;
 mod responsiveness_policy;
+mod reverse_deps_policy;
+mod script_policy;
 mod version_policy;
 
 pub use age_policy::AgePolicy;
 pub use aged_count_policy::AgedCountPolicy;
+pub use audit_coverage_policy::AuditCoveragePolicy;
 pub use boolean_policy::BooleanPolicy;
 pub use license_policy::LicensePolicy;
+pub use maintenance_status_policy::MaintenanceStatusPolicy;
 pub use max_count_policy::MaxCountPolicy;
+pub use max_score_policy::MaxScorePolicy;
 pub use min_count_policy::MinCountPolicy;
+pub use min_score_policy::MinScorePolicy;
+pub use msrv_policy::MsrvPolicy;
 pub use percentage_policy::PercentagePolicy;
 pub use responsiveness_policy::ResponsivenessPolicy;
+pub use reverse_deps_policy::ReverseDepsPolicy;
+pub use script_policy::{SCRIPT_NAME_DUPLICATE, ScriptPolicy};
 pub use version_policy::VersionPolicy;
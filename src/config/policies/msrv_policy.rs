@@ -0,0 +1,115 @@
+use crate::config::diagnostic::{Diagnostic, Severity};
+use crate::config::policy::{POLICY_DOMINANCE, Policy};
+use crate::facts::crates::RustEdition;
+use crate::metrics::Metric;
+use crate::misc::DependencyTypes;
+use semver::Version;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct MsrvPolicy {
+    #[serde(default)]
+    pub dependency_types: DependencyTypes,
+
+    /// Newest MSRV the consumer is willing to require, e.g. `"1.70"` or `"1.70.0"`.
+    /// Crates whose `rust_version` is at or below this version earn `points`.
+    pub max_rust_version: String,
+
+    /// Oldest edition the consumer is willing to require. Crates targeting an edition
+    /// older than this fail the policy even when their MSRV is in range.
+    #[serde(default)]
+    pub min_edition: Option<RustEdition>,
+
+    /// When `true`, a crate whose MSRV exceeds [`Self::max_rust_version`] doesn't fail the
+    /// policy outright; it's reported as a warning-level outcome instead, following the
+    /// cargo MSRV-aware resolver's "prefer compatible" idea.
+    #[serde(default)]
+    pub prefer_compatible: bool,
+
+    /// Points awarded for the warning-level outcome above, instead of an outright failure's
+    /// zero. Ignored unless [`Self::prefer_compatible`] is `true`. Defaults to `0.0`.
+    #[serde(default)]
+    pub warning_points: f64,
+
+    pub points: f64,
+}
+
+impl MsrvPolicy {
+    /// Parses a (possibly partial) semver string like `"1.70"`, filling missing components
+    /// with zero so crates.io's `rust_version` field can be compared against `Version`.
+    #[must_use]
+    pub fn parse_rust_version(raw: &str) -> Option<Version> {
+        normalize_version(raw)
+    }
+
+    /// Returns `true` if `rust_version` is at or below [`Self::max_rust_version`] and, when
+    /// [`Self::min_edition`] is configured, `edition` is at or above it.
+    #[must_use]
+    pub fn matches(&self, rust_version: &Version, edition: Option<RustEdition>) -> bool {
+        let Some(max_rust_version) = normalize_version(&self.max_rust_version) else {
+            return false;
+        };
+
+        if *rust_version > max_rust_version {
+            return false;
+        }
+
+        match self.min_edition {
+            Some(min_edition) => edition.is_some_and(|e| e >= min_edition),
+            None => true,
+        }
+    }
+}
+
+/// Parses a (possibly partial) semver string like `"1.70"`, filling missing components
+/// with zero so crates.io's `rust_version` field can be compared against `Version`.
+fn normalize_version(raw: &str) -> Option<Version> {
+    let mut parts = raw.trim().splitn(3, '.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next().map(str::parse).transpose().ok()?.unwrap_or(0);
+    let patch = parts.next().map(str::parse).transpose().ok()?.unwrap_or(0);
+    Some(Version::new(major, minor, patch))
+}
+
+impl Policy for MsrvPolicy {
+    fn dependency_types(&self) -> &DependencyTypes {
+        &self.dependency_types
+    }
+
+    fn points(&self) -> f64 {
+        self.points
+    }
+
+    fn validate<'a>(metric: Metric, policies: impl IntoIterator<Item = &'a Self>) -> Vec<Diagnostic>
+    where
+        Self: 'a,
+    {
+        let mut diagnostics = Vec::new();
+        let policies: Vec<_> = policies
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, p)| normalize_version(&p.max_rust_version).map(|v| (index, p, v)))
+            .collect();
+
+        for (pos, &(i, policy_a, ref version_a)) in policies.iter().enumerate() {
+            for &(j, policy_b, ref version_b) in policies.iter().skip(pos + 1) {
+                let overlap = policy_a.dependency_types().intersect(policy_b.dependency_types());
+                if overlap.is_empty() {
+                    continue;
+                }
+
+                if version_a >= version_b {
+                    diagnostics.push(Diagnostic::new(
+                        POLICY_DOMINANCE,
+                        Severity::Warning,
+                        format!("{metric}: Policy #{i} dominates policy #{j} for dependency types '{overlap}'"),
+                        vec![i, j],
+                    ));
+                }
+            }
+        }
+
+        diagnostics
+    }
+}
@@ -1,4 +1,5 @@
-use crate::config::policy::Policy;
+use crate::config::diagnostic::{Diagnostic, Severity};
+use crate::config::policy::{POLICY_DOMINANCE, Policy};
 use crate::metrics::Metric;
 use crate::misc::DependencyTypes;
 use serde::{Deserialize, Serialize};
@@ -27,11 +28,11 @@ impl Policy for ResponsivenessPolicy {
         self.points
     }
 
-    fn validate<'a>(metric: Metric, policies: impl IntoIterator<Item = &'a Self>) -> Vec<String>
+    fn validate<'a>(metric: Metric, policies: impl IntoIterator<Item = &'a Self>) -> Vec<Diagnostic>
     where
         Self: 'a,
     {
-        let mut warnings = Vec::new();
+        let mut diagnostics = Vec::new();
         let policies: Vec<_> = policies.into_iter().collect();
 
         for (i, policy_a) in policies.iter().enumerate() {
@@ -50,13 +51,16 @@ impl Policy for ResponsivenessPolicy {
                     && policy_a.max_p90_days >= policy_b.max_p90_days
                     && policy_a.max_p95_days >= policy_b.max_p95_days
                 {
-                    warnings.push(format!(
-                        "{metric}: Policy #{i} dominates policy #{j} for dependency type '{overlap}'"
+                    diagnostics.push(Diagnostic::new(
+                        POLICY_DOMINANCE,
+                        Severity::Warning,
+                        format!("{metric}: Policy #{i} dominates policy #{j} for dependency type '{overlap}'"),
+                        vec![i, j],
                     ));
                 }
             }
         }
 
-        warnings
+        diagnostics
     }
 }
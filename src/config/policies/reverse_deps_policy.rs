@@ -0,0 +1,68 @@
+use crate::config::diagnostic::{Diagnostic, Severity};
+use crate::config::policy::{POLICY_DOMINANCE, Policy};
+use crate::metrics::Metric;
+use crate::misc::DependencyTypes;
+use serde::{Deserialize, Serialize};
+
+/// Threshold on how many other crates on the registry depend on the crate under evaluation,
+/// modeled on crates.rs's `DepsStats`/`RevDependencies` split between required and optional
+/// reverse-dependency edges.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ReverseDepsPolicy {
+    #[serde(default)]
+    pub dependency_types: DependencyTypes,
+
+    /// Minimum number of reverse dependencies to earn `points`.
+    pub min_count: u32,
+
+    /// When `true`, only count reverse dependencies that pull this crate in as a required
+    /// (non-optional) dependency, ignoring ones that only reference it behind an optional
+    /// feature flag. Defaults to `false`, which counts both.
+    #[serde(default)]
+    pub required_only: bool,
+
+    pub points: f64,
+}
+
+impl Policy for ReverseDepsPolicy {
+    fn dependency_types(&self) -> &DependencyTypes {
+        &self.dependency_types
+    }
+
+    fn points(&self) -> f64 {
+        self.points
+    }
+
+    fn breakpoint(&self) -> Option<f64> {
+        Some(f64::from(self.min_count))
+    }
+
+    fn validate<'a>(metric: Metric, policies: impl IntoIterator<Item = &'a Self>) -> Vec<Diagnostic>
+    where
+        Self: 'a,
+    {
+        let mut diagnostics = Vec::new();
+        let policies: Vec<_> = policies.into_iter().collect();
+
+        for (i, policy_a) in policies.iter().enumerate() {
+            for (j, policy_b) in policies.iter().enumerate().skip(i + 1) {
+                let overlap = policy_a.dependency_types().intersect(policy_b.dependency_types());
+                if overlap.is_empty() {
+                    continue;
+                }
+
+                if policy_a.required_only == policy_b.required_only && policy_a.min_count <= policy_b.min_count {
+                    diagnostics.push(Diagnostic::new(
+                        POLICY_DOMINANCE,
+                        Severity::Warning,
+                        format!("{metric}: Policy #{i} dominates policy #{j} for dependency types '{overlap}'"),
+                        vec![i, j],
+                    ));
+                }
+            }
+        }
+
+        diagnostics
+    }
+}
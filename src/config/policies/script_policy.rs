@@ -0,0 +1,52 @@
+use crate::config::diagnostic::{Diagnostic, Severity};
+use crate::misc::DependencyTypes;
+use serde::{Deserialize, Serialize};
+
+/// [`Diagnostic::code`] for two `custom_metrics` scripts sharing a `name`, whose outcomes would
+/// otherwise overwrite each other in [`crate::ranking::RankingOutcome::custom_details`].
+pub const SCRIPT_NAME_DUPLICATE: &str = "script-name-duplicate";
+
+/// A custom metric expressed as a sandboxed Rhai script instead of a built-in [`crate::config::Policy`].
+///
+/// Unlike the threshold-based policies in this module, a script is evaluated for its own
+/// [`MetricCategory::Custom`](crate::metrics::MetricCategory::Custom) bucket rather than a fixed
+/// [`crate::metrics::Metric`], since its rule is arbitrary org-specific logic rather than a
+/// comparison against sibling policies. See `crate::ranking::script_engine` for the `facts`
+/// object bound in scope and the script's return-value contract.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScriptPolicy {
+    /// Unique name for this custom metric, used to key its outcome in
+    /// [`crate::ranking::RankingOutcome::custom_details`] and reports (e.g. `"owner-allowlist"`).
+    pub name: String,
+
+    #[serde(default)]
+    pub dependency_types: DependencyTypes,
+
+    /// Rhai source implementing the rule; see the struct docs for its contract.
+    pub script: String,
+}
+
+impl ScriptPolicy {
+    /// Flag scripts sharing a `name`, which would otherwise silently overwrite each other's
+    /// outcome once evaluated.
+    #[must_use]
+    pub fn validate(policies: &[Self]) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+
+        for (i, a) in policies.iter().enumerate() {
+            for (j, b) in policies.iter().enumerate().skip(i + 1) {
+                if a.name == b.name {
+                    diagnostics.push(Diagnostic::new(
+                        SCRIPT_NAME_DUPLICATE,
+                        Severity::Warning,
+                        format!("custom_metrics: policy #{i} and #{j} share the name '{}'", a.name),
+                        vec![i, j],
+                    ));
+                }
+            }
+        }
+
+        diagnostics
+    }
+}
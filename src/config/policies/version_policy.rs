@@ -0,0 +1,56 @@
+use crate::config::diagnostic::{Diagnostic, Severity};
+use crate::config::policy::{POLICY_DOMINANCE, Policy};
+use crate::metrics::Metric;
+use crate::misc::DependencyTypes;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct VersionPolicy {
+    #[serde(default)]
+    pub dependency_types: DependencyTypes,
+
+    pub min_major_version: u32,
+
+    pub points: f64,
+}
+
+impl Policy for VersionPolicy {
+    fn dependency_types(&self) -> &DependencyTypes {
+        &self.dependency_types
+    }
+
+    fn points(&self) -> f64 {
+        self.points
+    }
+
+    fn validate<'a>(metric: Metric, policies: impl IntoIterator<Item = &'a Self>) -> Vec<Diagnostic>
+    where
+        Self: 'a,
+    {
+        let mut diagnostics = Vec::new();
+        let policies: Vec<_> = policies.into_iter().collect();
+
+        for (i, policy_a) in policies.iter().enumerate() {
+            for (j, policy_b) in policies.iter().enumerate().skip(i + 1) {
+                let policy_a = *policy_a;
+                let policy_b = *policy_b;
+                let overlap = policy_a.dependency_types().intersect(policy_b.dependency_types());
+                if overlap.is_empty() {
+                    continue;
+                }
+
+                if policy_a.min_major_version <= policy_b.min_major_version {
+                    diagnostics.push(Diagnostic::new(
+                        POLICY_DOMINANCE,
+                        Severity::Warning,
+                        format!("{metric}: Policy #{i} dominates policy #{j} for dependency types '{overlap}'"),
+                        vec![i, j],
+                    ));
+                }
+            }
+        }
+
+        diagnostics
+    }
+}
@@ -1,8 +1,18 @@
 //! Common trait for policy validation
 
+use crate::config::Diagnostic;
 use crate::metrics::Metric;
 use crate::misc::DependencyTypes;
 
+/// [`Diagnostic::code`] for a finding that one policy's thresholds make a later policy
+/// unreachable for the dependency types they share (e.g. a looser threshold listed first).
+pub const POLICY_DOMINANCE: &str = "policy-dominance";
+
+/// [`Diagnostic::code`] for a finding that two policies cover the same dependency types with
+/// no threshold to differentiate precedence between them (boolean/audit-coverage/
+/// maintenance-status-style policies, where any overlap is necessarily redundant).
+pub const POLICY_DUPLICATE: &str = "policy-duplicate";
+
 /// Common interface for all policy types
 pub trait Policy: Sized {
     /// Get the dependency types this policy applies to
@@ -11,8 +21,17 @@ pub trait Policy: Sized {
     /// Get the score for this policy
     fn points(&self) -> f64;
 
-    /// Validate policies and return warnings
-    fn validate<'a>(metric: Metric, policies: impl IntoIterator<Item = &'a Self>) -> Vec<String>
+    /// The threshold value at which this policy's full `points()` are earned, used to
+    /// linearly interpolate points between adjacent threshold policies in graded-scoring
+    /// mode (see `MetricCalculator::apply_graded_policy`). `None` for policies with no
+    /// single numeric threshold (e.g. license or boolean policies), which can't be graded.
+    fn breakpoint(&self) -> Option<f64> {
+        None
+    }
+
+    /// Validate policies and return structured diagnostics (see [`POLICY_DOMINANCE`] and
+    /// [`POLICY_DUPLICATE`] for the codes emitted by the built-in impls).
+    fn validate<'a>(metric: Metric, policies: impl IntoIterator<Item = &'a Self>) -> Vec<Diagnostic>
     where
         Self: 'a;
 }
@@ -1,7 +1,18 @@
-use chrono::{DateTime, Utc};
+use super::{AdvisoryRecord, DependencyGraph, base_score};
+use crate::facts::AgeStats;
+use chrono::{DateTime, NaiveDate, Utc};
 use rustsec::advisory::{Informational, Severity};
+use rustsec::platforms::target::{Arch, OS};
+use semver::Version;
 use serde::{Deserialize, Serialize};
 
+/// A platform this crate ships to, used to filter out advisories that can't affect it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Target {
+    pub arch: Arch,
+    pub os: OS,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct AdvisoryData {
     pub timestamp: DateTime<Utc>,
@@ -10,6 +21,17 @@ pub struct AdvisoryData {
     pub medium_vulnerability_count: u64,
     pub high_vulnerability_count: u64,
     pub critical_vulnerability_count: u64,
+
+    /// Sum of CVSS v3.1 base scores across vulnerabilities affecting the analyzed version,
+    /// re-derived from each advisory's vector rather than trusting the NVD severity bucket.
+    /// Advisories with no CVSS vector, or one that fails to parse, contribute `0.0`.
+    pub cvss_weighted_vulnerability_score: f64,
+
+    /// Number of vulnerabilities affecting the analyzed version whose CVSS vector was
+    /// missing or failed strict parsing, and so are absent from
+    /// [`Self::cvss_weighted_vulnerability_score`].
+    pub cvss_parse_failures: u64,
+
     pub warning_count: u64,
     pub notice_warning_count: u64,
     pub unmaintained_warning_count: u64,
@@ -20,15 +42,56 @@ pub struct AdvisoryData {
     pub historical_medium_vulnerability_count: u64,
     pub historical_high_vulnerability_count: u64,
     pub historical_critical_vulnerability_count: u64,
+
+    /// Same as [`Self::cvss_weighted_vulnerability_score`], but summed over every historical
+    /// vulnerability rather than just those affecting the analyzed version.
+    pub historical_cvss_weighted_vulnerability_score: f64,
+
+    /// Same as [`Self::cvss_parse_failures`], but counted over every historical vulnerability.
+    pub historical_cvss_parse_failures: u64,
+
     pub historical_warning_count: u64,
     pub historical_notice_warning_count: u64,
     pub historical_unmaintained_warning_count: u64,
     pub historical_unsound_warning_count: u64,
     pub historical_yanked_warning_count: u64,
+
+    /// Structured detail for every distinct advisory affecting this crate, current and
+    /// historical alike. Advisories sharing a `RUSTSEC-*` id, `GHSA-*` id, or `CVE-*` alias
+    /// are merged into a single record rather than counted twice.
+    pub records: Vec<AdvisoryRecord>,
+
+    /// Distribution of days between each historical advisory's publication and the first
+    /// release that resolved it, i.e. how quickly this crate has historically shipped
+    /// security fixes. Advisories with no identifiable patched release don't contribute a
+    /// sample.
+    pub patch_responsiveness: AgeStats,
+
+    /// Raw day-deltas feeding [`Self::patch_responsiveness`], accumulated by
+    /// `record_patch_latency` and reduced into stats by `finalize_patch_responsiveness`.
+    #[serde(skip)]
+    patch_latency_days: Vec<u32>,
 }
 
 impl AdvisoryData {
-    pub(super) fn count_advisory_for_version(&mut self, advisory: &rustsec::Advisory) {
+    pub(super) fn count_advisory_for_version(
+        &mut self,
+        advisory: &rustsec::Advisory,
+        version: &Version,
+        targets: &[Target],
+        dependency_graph: Option<&DependencyGraph>,
+        only_count_reachable: bool,
+    ) {
+        if !Self::advisory_affects_version(advisory, version) {
+            return;
+        }
+
+        let reachable = Self::package_reachable(advisory, dependency_graph);
+
+        if Self::advisory_affects_targets(advisory, targets) {
+            self.record_advisory(advisory, true, dependency_graph, reachable);
+        }
+
         let mut warning_counts = [
             &mut self.warning_count,
             &mut self.notice_warning_count,
@@ -42,10 +105,35 @@ impl AdvisoryData {
             &mut self.high_vulnerability_count,
             &mut self.critical_vulnerability_count,
         ];
-        Self::apply_advisory_counts(advisory, &mut warning_counts, &mut vulnerability_counts);
+        Self::apply_advisory_counts(
+            advisory,
+            targets,
+            &mut warning_counts,
+            &mut vulnerability_counts,
+            &mut self.cvss_weighted_vulnerability_score,
+            &mut self.cvss_parse_failures,
+            only_count_reachable && !reachable,
+        );
     }
 
-    pub(super) fn count_advisory_historical(&mut self, advisory: &rustsec::Advisory) {
+    pub(super) fn count_advisory_historical(
+        &mut self,
+        advisory: &rustsec::Advisory,
+        targets: &[Target],
+        dependency_graph: Option<&DependencyGraph>,
+        only_count_reachable: bool,
+        resolved_at: Option<DateTime<Utc>>,
+    ) {
+        let reachable = Self::package_reachable(advisory, dependency_graph);
+
+        if Self::advisory_affects_targets(advisory, targets) {
+            self.record_advisory(advisory, false, dependency_graph, reachable);
+        }
+
+        if let Some(resolved_at) = resolved_at {
+            self.record_patch_latency(advisory, resolved_at);
+        }
+
         let mut warning_counts = [
             &mut self.historical_warning_count,
             &mut self.historical_notice_warning_count,
@@ -59,10 +147,119 @@ impl AdvisoryData {
             &mut self.historical_high_vulnerability_count,
             &mut self.historical_critical_vulnerability_count,
         ];
-        Self::apply_advisory_counts(advisory, &mut warning_counts, &mut vulnerability_counts);
+        Self::apply_advisory_counts(
+            advisory,
+            targets,
+            &mut warning_counts,
+            &mut vulnerability_counts,
+            &mut self.historical_cvss_weighted_vulnerability_score,
+            &mut self.historical_cvss_parse_failures,
+            only_count_reachable && !reachable,
+        );
+    }
+
+    /// Returns `true` if `advisory`'s package is reachable under the enabled feature set, or
+    /// if no `dependency_graph` was supplied to check against.
+    fn package_reachable(advisory: &rustsec::Advisory, dependency_graph: Option<&DependencyGraph>) -> bool {
+        dependency_graph.is_none_or(|graph| graph.shortest_path(&advisory.metadata.package.to_string(), true).is_some())
+    }
+
+    /// Records the number of days between `advisory`'s publication and `resolved_at`, the
+    /// release date of the first version that resolved it, for later inclusion in
+    /// [`Self::patch_responsiveness`]. A malformed or future-dated advisory timestamp is
+    /// silently dropped rather than skewing the distribution.
+    fn record_patch_latency(&mut self, advisory: &rustsec::Advisory, resolved_at: DateTime<Utc>) {
+        let Ok(published) = NaiveDate::parse_from_str(&advisory.metadata.date.to_string(), "%Y-%m-%d") else {
+            return;
+        };
+        let Some(published) = published.and_hms_opt(0, 0, 0) else {
+            return;
+        };
+        let days = (resolved_at - published.and_utc()).num_days();
+        if let Ok(days) = u32::try_from(days) {
+            self.patch_latency_days.push(days);
+        }
+    }
+
+    /// Builds [`Self::patch_responsiveness`] from the latencies accumulated via
+    /// `record_patch_latency`. Must be called once after every historical advisory for the
+    /// crate has been processed.
+    pub(super) fn finalize_patch_responsiveness(&mut self) {
+        self.patch_responsiveness = AgeStats::from_days(&self.patch_latency_days);
+    }
+
+    /// Records `advisory`'s detail, merging it into an existing record if it shares a
+    /// `RUSTSEC-*` id, `GHSA-*` id, or `CVE-*` alias with one already seen. `affects_current_version`
+    /// is OR'd into the existing record so an advisory seen as historical-only in one pass is
+    /// still flagged once another pass finds it affects the analyzed version.
+    fn record_advisory(
+        &mut self,
+        advisory: &rustsec::Advisory,
+        affects_current_version: bool,
+        dependency_graph: Option<&DependencyGraph>,
+        reachable: bool,
+    ) {
+        if let Some(existing) = self.records.iter_mut().find(|record| record.cross_references(advisory)) {
+            existing.affects_current_version |= affects_current_version;
+            return;
+        }
+
+        let dependency_path = dependency_graph.and_then(|graph| graph.shortest_path(&advisory.metadata.package.to_string(), false));
+        self.records
+            .push(AdvisoryRecord::from_advisory(advisory, affects_current_version, dependency_path, reachable));
+    }
+
+    /// Returns `true` if the given version is actually affected by `advisory`.
+    ///
+    /// A version is affected if and only if it matches none of the advisory's `patched`
+    /// requirements and none of its `unaffected` requirements. Advisories that carry no
+    /// `[versions]` section at all are treated as affecting every version, matching
+    /// `cargo-audit`'s behavior.
+    fn advisory_affects_version(advisory: &rustsec::Advisory, version: &Version) -> bool {
+        let Some(versions) = &advisory.versions else {
+            return true;
+        };
+
+        let is_patched = versions.patched().iter().any(|req| req.matches(version));
+        let is_unaffected = versions.unaffected().iter().any(|req| req.matches(version));
+
+        !is_patched && !is_unaffected
     }
 
-    fn apply_advisory_counts(advisory: &rustsec::Advisory, warning_counts: &mut [&mut u64; 4], vulnerability_counts: &mut [&mut u64; 5]) {
+    /// Returns `true` if at least one of the configured `targets` is not excluded by the
+    /// advisory's `affected.arch`/`affected.os` constraints.
+    ///
+    /// When `targets` is empty (the user hasn't declared which platforms they ship to),
+    /// every advisory is counted, matching prior behavior.
+    fn advisory_affects_targets(advisory: &rustsec::Advisory, targets: &[Target]) -> bool {
+        if targets.is_empty() {
+            return true;
+        }
+
+        let Some(affected) = &advisory.affected else {
+            return true;
+        };
+
+        targets.iter().any(|target| {
+            let arch_ok = affected.arch.as_ref().is_none_or(|archs| archs.contains(&target.arch));
+            let os_ok = affected.os.as_ref().is_none_or(|oses| oses.contains(&target.os));
+            arch_ok && os_ok
+        })
+    }
+
+    fn apply_advisory_counts(
+        advisory: &rustsec::Advisory,
+        targets: &[Target],
+        warning_counts: &mut [&mut u64; 4],
+        vulnerability_counts: &mut [&mut u64; 5],
+        cvss_weighted_score: &mut f64,
+        cvss_parse_failures: &mut u64,
+        exclude_vulnerability: bool,
+    ) {
+        if !Self::advisory_affects_targets(advisory, targets) {
+            return;
+        }
+
         if let Some(informational) = &advisory.metadata.informational {
             *warning_counts[0] += 1; // total warning count
             match informational {
@@ -75,16 +272,31 @@ impl AdvisoryData {
             return;
         }
 
+        // A package only reachable through a non-default/optional feature can't actually
+        // affect a build with the enabled feature set, so it's excluded from the
+        // vulnerability count, severity buckets, and CVSS-weighted score.
+        if exclude_vulnerability {
+            return;
+        }
+
         *vulnerability_counts[0] += 1; // total vulnerability count
 
-        if let Some(cvss) = &advisory.metadata.cvss {
-            match cvss.severity() {
-                Severity::None => {}
-                Severity::Low => *vulnerability_counts[1] += 1,
-                Severity::Medium => *vulnerability_counts[2] += 1,
-                Severity::High => *vulnerability_counts[3] += 1,
-                Severity::Critical => *vulnerability_counts[4] += 1,
+        match &advisory.metadata.cvss {
+            Some(cvss) => {
+                match cvss.severity() {
+                    Severity::None => {}
+                    Severity::Low => *vulnerability_counts[1] += 1,
+                    Severity::Medium => *vulnerability_counts[2] += 1,
+                    Severity::High => *vulnerability_counts[3] += 1,
+                    Severity::Critical => *vulnerability_counts[4] += 1,
+                }
+
+                match base_score(&cvss.to_string()) {
+                    Ok(score) => *cvss_weighted_score += score,
+                    Err(_) => *cvss_parse_failures += 1,
+                }
             }
+            None => *cvss_parse_failures += 1,
         }
     }
 }
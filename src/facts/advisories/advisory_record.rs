@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+
+/// A single advisory, preserving the detail that the aggregate counts in [`super::AdvisoryData`]
+/// collapse away.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdvisoryRecord {
+    /// The advisory's primary identifier, e.g. `RUSTSEC-2023-0001`.
+    pub id: String,
+
+    /// Other identifiers the same underlying issue is known by, e.g. `GHSA-xxxx` or
+    /// `CVE-2023-xxxx`. Used to deduplicate advisories reported under multiple ids.
+    pub aliases: Vec<String>,
+
+    pub title: String,
+    pub description: String,
+
+    /// `None` for informational advisories (unmaintained/notice/unsound), which carry no
+    /// CVSS score.
+    pub severity: Option<String>,
+
+    /// The CVSS vector string (e.g. `CVSS:3.1/AV:N/...`), if the advisory scored one.
+    pub cvss_vector: Option<String>,
+
+    /// Link to the advisory's writeup, if the advisory metadata carries one.
+    pub url: Option<String>,
+
+    /// Whether this advisory affects the specific version being analyzed, as opposed to
+    /// only appearing in the crate's historical advisory record.
+    pub affects_current_version: bool,
+
+    /// Shortest dependency chain from a workspace root to the advised-about package, e.g.
+    /// `["myapp", "tokio", "vulnerable-crate"]`, if a dependency graph was supplied.
+    pub dependency_path: Option<Vec<String>>,
+
+    /// Whether the advised-about package is reachable under the enabled feature set, as
+    /// opposed to only through a non-default/optional feature. `true` when no dependency
+    /// graph was supplied, since reachability can't be ruled out without one.
+    pub reachable: bool,
+}
+
+impl AdvisoryRecord {
+    pub(super) fn from_advisory(
+        advisory: &rustsec::Advisory,
+        affects_current_version: bool,
+        dependency_path: Option<Vec<String>>,
+        reachable: bool,
+    ) -> Self {
+        Self {
+            id: advisory.metadata.id.to_string(),
+            aliases: advisory.metadata.aliases.iter().map(ToString::to_string).collect(),
+            title: advisory.metadata.title.clone(),
+            description: advisory.metadata.description.clone(),
+            severity: advisory.metadata.cvss.as_ref().map(|cvss| cvss.severity().to_string()),
+            cvss_vector: advisory.metadata.cvss.as_ref().map(ToString::to_string),
+            url: advisory.metadata.url.as_ref().map(ToString::to_string),
+            affects_current_version,
+            dependency_path,
+            reachable,
+        }
+    }
+
+    /// Returns `true` if `advisory` refers to the same underlying issue as `self`, via a
+    /// shared id or alias (e.g. the same flaw reported under both a `RUSTSEC-*` id and a
+    /// `GHSA-*`/`CVE-*` alias).
+    pub(super) fn cross_references(&self, advisory: &rustsec::Advisory) -> bool {
+        let other_id = advisory.metadata.id.to_string();
+        let other_aliases: Vec<String> = advisory.metadata.aliases.iter().map(ToString::to_string).collect();
+
+        self.id == other_id
+            || other_aliases.contains(&self.id)
+            || self.aliases.contains(&other_id)
+            || self.aliases.iter().any(|alias| other_aliases.contains(alias))
+    }
+}
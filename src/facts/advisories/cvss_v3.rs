@@ -0,0 +1,122 @@
+//! Pure CVSS v3.1 base-score computation from a vector string.
+//!
+//! Re-derives the score directly from the vector rather than trusting the NVD-assigned
+//! severity bucket on [`super::AdvisoryRecord`], so a crate can be gated on actual computed
+//! exploitability instead of a coarse low/medium/high/critical label.
+
+use std::collections::HashMap;
+
+/// A CVSS v3.1 vector string failed strict parsing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CvssParseError(pub String);
+
+impl core::fmt::Display for CvssParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "invalid CVSS v3.1 vector: {}", self.0)
+    }
+}
+
+/// Compute the CVSS v3.1 base score for a vector string such as
+/// `CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H`.
+///
+/// # Errors
+///
+/// Returns [`CvssParseError`] if the string doesn't declare the `CVSS:3.1` prefix, or is
+/// missing any of the eight base metrics (AV, AC, PR, UI, S, C, I, A).
+pub fn base_score(vector: &str) -> Result<f64, CvssParseError> {
+    let mut segments = vector.split('/');
+    let prefix = segments.next().ok_or_else(|| CvssParseError(vector.to_string()))?;
+    if prefix != "CVSS:3.1" {
+        return Err(CvssParseError(vector.to_string()));
+    }
+
+    let mut metrics: HashMap<&str, &str> = HashMap::new();
+    for segment in segments {
+        let (key, value) = segment.split_once(':').ok_or_else(|| CvssParseError(vector.to_string()))?;
+        _ = metrics.insert(key, value);
+    }
+
+    let scope_changed = matches!(metrics.get("S"), Some(&"C"));
+    if !matches!(metrics.get("S"), Some(&"C" | &"U")) {
+        return Err(CvssParseError(vector.to_string()));
+    }
+
+    let av = match metrics.get("AV") {
+        Some(&"N") => 0.85,
+        Some(&"A") => 0.62,
+        Some(&"L") => 0.55,
+        Some(&"P") => 0.20,
+        _ => return Err(CvssParseError(vector.to_string())),
+    };
+    let ac = match metrics.get("AC") {
+        Some(&"L") => 0.77,
+        Some(&"H") => 0.44,
+        _ => return Err(CvssParseError(vector.to_string())),
+    };
+    let pr = match metrics.get("PR") {
+        Some(&"N") => 0.85,
+        Some(&"L") => {
+            if scope_changed {
+                0.68
+            } else {
+                0.62
+            }
+        }
+        Some(&"H") => {
+            if scope_changed {
+                0.50
+            } else {
+                0.27
+            }
+        }
+        _ => return Err(CvssParseError(vector.to_string())),
+    };
+    let ui = match metrics.get("UI") {
+        Some(&"N") => 0.85,
+        Some(&"R") => 0.62,
+        _ => return Err(CvssParseError(vector.to_string())),
+    };
+
+    let impact_metric = |key: &str| -> Result<f64, CvssParseError> {
+        match metrics.get(key) {
+            Some(&"H") => Ok(0.56),
+            Some(&"L") => Ok(0.22),
+            Some(&"N") => Ok(0.0),
+            _ => Err(CvssParseError(vector.to_string())),
+        }
+    };
+    let c = impact_metric("C")?;
+    let i = impact_metric("I")?;
+    let a = impact_metric("A")?;
+
+    let iss = 1.0 - (1.0 - c) * (1.0 - i) * (1.0 - a);
+    let impact = if scope_changed {
+        7.52 * (iss - 0.029) - 3.25 * (iss - 0.02).powf(15.0)
+    } else {
+        6.42 * iss
+    };
+
+    if impact <= 0.0 {
+        return Ok(0.0);
+    }
+
+    let exploitability = 8.22 * av * ac * pr * ui;
+    let raw = if scope_changed { 1.08 * (impact + exploitability) } else { impact + exploitability };
+
+    Ok(round_up_to_tenth(raw.min(10.0)))
+}
+
+/// The CVSS spec's "Roundup" function: round up to the nearest 0.1.
+#[expect(clippy::cast_possible_truncation, reason = "value is pre-clamped to [0, 10], so the scaled integer fits in i64")]
+fn round_up_to_tenth(value: f64) -> f64 {
+    let int_input = (value * 100_000.0).round() as i64;
+    if int_input % 10_000 == 0 {
+        #[expect(clippy::cast_precision_loss, reason = "int_input is bounded by a score in [0, 10] scaled by 100_000")]
+        let exact = int_input as f64 / 100_000.0;
+        exact
+    } else {
+        #[expect(clippy::cast_precision_loss, reason = "int_input is bounded by a score in [0, 10] scaled by 100_000")]
+        let rounded = (int_input / 10_000 + 1) as f64 / 10.0;
+        rounded
+    }
+}
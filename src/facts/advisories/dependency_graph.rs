@@ -0,0 +1,89 @@
+//! Minimal directed dependency graph, used to report the shortest dependency chain from a
+//! workspace root to a vulnerable package and to tell whether that package is reachable
+//! under the enabled feature set.
+
+use std::collections::{HashMap, VecDeque};
+
+/// A directed edge from a parent package to one of its resolved dependencies.
+#[derive(Debug, Clone)]
+pub struct DependencyEdge {
+    pub parent: String,
+    pub child: String,
+
+    /// `false` if this edge only exists behind a non-default or optional feature that
+    /// isn't part of the enabled feature set, mirroring how `cargo-audit` walks edge
+    /// directions to collapse duplicate advisories about the same package.
+    pub reachable: bool,
+}
+
+/// A directed graph of resolved packages built from a lockfile: nodes are package names,
+/// edges are parent → child dependency relationships.
+#[derive(Debug, Clone, Default)]
+pub struct DependencyGraph {
+    /// Workspace member packages the graph is rooted at.
+    pub roots: Vec<String>,
+    pub edges: Vec<DependencyEdge>,
+}
+
+impl DependencyGraph {
+    #[must_use]
+    pub fn new(roots: Vec<String>, edges: Vec<DependencyEdge>) -> Self {
+        Self { roots, edges }
+    }
+
+    /// Breadth-first shortest path from any workspace root to `package`, returning the
+    /// full chain `root → ... → package`, or `None` if `package` isn't reachable.
+    ///
+    /// When `reachable_only` is set, edges that only exist behind a non-default/optional
+    /// feature are excluded from the search, so a package reachable only that way reports
+    /// as unreachable.
+    #[must_use]
+    pub fn shortest_path(&self, package: &str, reachable_only: bool) -> Option<Vec<String>> {
+        let mut adjacency: HashMap<&str, Vec<&str>> = HashMap::new();
+        for edge in &self.edges {
+            if reachable_only && !edge.reachable {
+                continue;
+            }
+            adjacency.entry(edge.parent.as_str()).or_default().push(edge.child.as_str());
+        }
+
+        let mut parents: HashMap<&str, &str> = HashMap::new();
+        let mut queue: VecDeque<&str> = VecDeque::new();
+        for root in &self.roots {
+            if parents.insert(root.as_str(), root.as_str()).is_none() {
+                queue.push_back(root.as_str());
+            }
+        }
+
+        while let Some(current) = queue.pop_front() {
+            if current == package {
+                return Some(Self::reconstruct(&parents, current));
+            }
+            if let Some(children) = adjacency.get(current) {
+                for &child in children {
+                    if !parents.contains_key(child) {
+                        _ = parents.insert(child, current);
+                        queue.push_back(child);
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Walks `parents` back from `target` to the root that discovered it.
+    fn reconstruct(parents: &HashMap<&str, &str>, target: &str) -> Vec<String> {
+        let mut path = vec![target.to_string()];
+        let mut current = target;
+        while let Some(&parent) = parents.get(current) {
+            if parent == current {
+                break;
+            }
+            path.push(parent.to_string());
+            current = parent;
+        }
+        path.reverse();
+        path
+    }
+}
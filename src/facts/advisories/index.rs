@@ -0,0 +1,130 @@
+//! rkyv-archived package-name to advisory index, caching a full `rustsec::Database` scan so
+//! repeated runs don't have to walk every advisory to find the handful that apply to a crate.
+//!
+//! [`super::Provider`] is the caller: on construction it compares the advisory git checkout's
+//! current HEAD commit against [`AdvisoryIndex::built_from_commit`] via [`AdvisoryIndex::load`],
+//! rebuilding with [`AdvisoryIndex::build`] and persisting with [`AdvisoryIndex::write_to`] on
+//! any mismatch or cache miss. `Provider::scan` then does a single [`ArchivedIndexHandle::get`]
+//! lookup per crate instead of iterating `database.iter()`.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use rkyv::{Archive, Deserialize, Serialize};
+
+/// Name of the cache file written next to the advisory checkout's `last_synced.json`.
+const INDEX_FILE_NAME: &str = "advisory_index.rkyv";
+
+/// One advisory's package-scoped detail, as stored in [`AdvisoryIndex`]. `Provider::scan` uses
+/// only [`Self::id`] from it, to fetch the full `rustsec::Advisory` from the opened database by
+/// key for `AdvisoryData::count_advisory_for_version`/`count_advisory_historical`; the other
+/// fields are stored but not read there.
+#[derive(Debug, Clone, Archive, Serialize, Deserialize)]
+#[archive(check_bytes)]
+pub struct IndexedAdvisory {
+    pub id: String,
+    pub aliases: Vec<String>,
+
+    /// Patched version ranges, kept as their `Display` form since `rustsec`'s `VersionReq`
+    /// types don't implement `rkyv`'s archive traits.
+    pub patched_versions: Vec<String>,
+    pub unaffected_versions: Vec<String>,
+    pub withdrawn: bool,
+}
+
+/// rkyv-archived package name to advisory list, built once per advisory database checkout and
+/// reused across runs until the checkout's HEAD commit changes.
+#[derive(Debug, Clone, Archive, Serialize, Deserialize)]
+#[archive(check_bytes)]
+pub struct AdvisoryIndex {
+    /// Commit hash of the advisory git repo this index was built from. A mismatch against the
+    /// checkout's current HEAD means the cache is stale and must be rebuilt before trusting it.
+    pub built_from_commit: String,
+    pub by_package: HashMap<String, Vec<IndexedAdvisory>>,
+}
+
+impl AdvisoryIndex {
+    /// Build a fresh index by walking every advisory in `database`, grouping by affected
+    /// package name.
+    #[must_use]
+    pub fn build(database: &rustsec::Database, commit: &str) -> Self {
+        let mut by_package: HashMap<String, Vec<IndexedAdvisory>> = HashMap::new();
+        for advisory in database.iter() {
+            let indexed = IndexedAdvisory {
+                id: advisory.metadata.id.to_string(),
+                aliases: advisory.metadata.aliases.iter().map(ToString::to_string).collect(),
+                patched_versions: advisory.versions.patched().iter().map(ToString::to_string).collect(),
+                unaffected_versions: advisory.versions.unaffected().iter().map(ToString::to_string).collect(),
+                withdrawn: advisory.metadata.withdrawn.is_some(),
+            };
+            by_package.entry(advisory.metadata.package.to_string()).or_default().push(indexed);
+        }
+        Self { built_from_commit: commit.to_string(), by_package }
+    }
+
+    /// Serialize `self` with rkyv and write it to `dir`'s [`INDEX_FILE_NAME`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization or the write fails.
+    pub fn write_to(&self, dir: &Path) -> io::Result<()> {
+        let bytes = rkyv::to_bytes::<_, 4096>(self).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))?;
+        fs::write(index_path(dir), &bytes[..])
+    }
+
+    /// Memory-map `dir`'s [`INDEX_FILE_NAME`] and return the archived index if present, valid,
+    /// and built from `expected_commit`. Returns `Ok(None)` on a cache miss (missing file,
+    /// corrupt archive, or stale commit) rather than erroring, since a miss just means the
+    /// caller should rebuild via [`Self::build`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only if the file exists but can't be `mmap`ed.
+    pub fn load(dir: &Path, expected_commit: &str) -> io::Result<Option<ArchivedIndexHandle>> {
+        let path = index_path(dir);
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let file = fs::File::open(&path)?;
+        // Safety: within this crate `advisory_index.rkyv` is only written by `write_to`, which
+        // `Provider` calls before it loads the index, never while a handle it holds is alive.
+        // `write_to` is a plain `fs::write` and no file lock is taken, so another process
+        // rewriting the file while it is mapped is not guarded against.
+        let mmap = unsafe { memmap2::Mmap::map(&file)? };
+
+        let Ok(archived) = rkyv::check_archived_root::<Self>(&mmap) else {
+            return Ok(None);
+        };
+
+        if archived.built_from_commit.as_str() != expected_commit {
+            return Ok(None);
+        }
+
+        Ok(Some(ArchivedIndexHandle { mmap }))
+    }
+}
+
+/// An rkyv-archived [`AdvisoryIndex`] backed by an `mmap`, already validated against the
+/// advisory repo's current HEAD commit. Fields are read directly off the archive via
+/// [`Self::get`] without deserializing it.
+pub struct ArchivedIndexHandle {
+    mmap: memmap2::Mmap,
+}
+
+impl ArchivedIndexHandle {
+    /// Advisories affecting `package`, read directly from the archived index. Empty if
+    /// `package` has none.
+    #[must_use]
+    pub fn get(&self, package: &str) -> &[rkyv::Archived<IndexedAdvisory>] {
+        // Safety: `mmap` was validated by `check_archived_root` in `AdvisoryIndex::load`.
+        let archived = unsafe { rkyv::archived_root::<AdvisoryIndex>(&self.mmap) };
+        archived.by_package.get(package).map_or(&[], |advisories| advisories.as_slice())
+    }
+}
+
+fn index_path(dir: &Path) -> PathBuf {
+    dir.join(INDEX_FILE_NAME)
+}
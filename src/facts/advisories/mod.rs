@@ -1,7 +1,17 @@
 //! `RustSec`vulnerability advisory database fact provider.
 
 mod advisory_data;
+mod advisory_record;
+mod cvss_v3;
+mod dependency_graph;
+mod index;
 mod provider;
+mod sync_state;
 
 pub use advisory_data::AdvisoryData;
+pub use advisory_record::AdvisoryRecord;
+pub use cvss_v3::{CvssParseError, base_score};
+pub use dependency_graph::{DependencyEdge, DependencyGraph};
+pub use index::{AdvisoryIndex, ArchivedIndexHandle, IndexedAdvisory};
 pub use provider::Provider;
+pub use sync_state::{DEFAULT_TTL, SyncState, needs_fetch};
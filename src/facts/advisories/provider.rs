@@ -0,0 +1,110 @@
+//! Fetches and exposes the `RustSec` advisory database for per-crate vulnerability scanning.
+//!
+//! Keeps a local partial-clone checkout in sync (via [`crate::misc::git`] and [`SyncState`])
+//! and caches a package-name-keyed [`AdvisoryIndex`] next to it, so [`Provider::scan`] touches
+//! only the advisories that actually mention one of the queried crates instead of walking the
+//! whole `rustsec::Database` per crate.
+//!
+//! Nothing in this tree yet populates [`crate::facts::CrateFacts`] for any fact source (there's
+//! no orchestrator calling any of the `crates`/`hosting`/`docs`/etc. providers either) — wiring
+//! `Provider::scan`'s output into an actual appraisal run is out of scope here.
+
+use super::AdvisoryData;
+use super::index::{AdvisoryIndex, ArchivedIndexHandle};
+use super::sync_state::{DEFAULT_TTL, SyncState, needs_fetch};
+use crate::facts::ProviderResult;
+use crate::facts::crate_spec::CrateSpec;
+use crate::misc::git;
+use crate::telemetry::Telemetry;
+use rustsec::repository::git::DEFAULT_URL;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// [`Telemetry`] provider label for every counter/histogram this module records.
+const LOG_TARGET: &str = "advisories";
+
+/// `RustSec` vulnerability advisory database, synced to a local checkout and indexed for
+/// per-crate lookup.
+pub struct Provider {
+    database: rustsec::Database,
+    index: ArchivedIndexHandle,
+    telemetry: Arc<Telemetry>,
+}
+
+impl Provider {
+    /// Ensure `checkout_dir` holds a fresh-enough advisory database checkout (syncing it first
+    /// if [`needs_fetch`] says so), then open it and load or rebuild its [`AdvisoryIndex`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sync, database open, or index build/load fails.
+    pub async fn new(checkout_dir: PathBuf, telemetry: Arc<Telemetry>) -> anyhow::Result<Self> {
+        let blocking_telemetry = Arc::clone(&telemetry);
+        tokio::task::spawn_blocking(move || Self::new_blocking(&checkout_dir, blocking_telemetry)).await?
+    }
+
+    fn new_blocking(checkout_dir: &Path, telemetry: Arc<Telemetry>) -> anyhow::Result<Self> {
+        if needs_fetch(checkout_dir)? {
+            telemetry.time("sync_duration", LOG_TARGET, || git::sync_partial_clone(checkout_dir, DEFAULT_URL, git::DEFAULT_GIT_TIMEOUT))?;
+            SyncState::new(DEFAULT_TTL).save(checkout_dir)?;
+        }
+
+        let database = telemetry.time("database_open_duration", LOG_TARGET, || rustsec::Database::open(checkout_dir))?;
+        let commit = git::head_commit(checkout_dir, git::DEFAULT_GIT_TIMEOUT)?;
+
+        let index = match AdvisoryIndex::load(checkout_dir, &commit)? {
+            Some(handle) => handle,
+            None => {
+                AdvisoryIndex::build(&database, &commit).write_to(checkout_dir)?;
+                AdvisoryIndex::load(checkout_dir, &commit)?
+                    .ok_or_else(|| anyhow::anyhow!("advisory index we just wrote is missing or corrupt"))?
+            }
+        };
+
+        Ok(Self { database, index, telemetry })
+    }
+
+    /// Look up advisory data for each of `crates`, in `O(crates + matches)` via
+    /// [`AdvisoryIndex`] rather than walking every advisory in the database for every crate.
+    /// A crate the index has no entry for is `Found` with a default (no advisories)
+    /// [`AdvisoryData`], matching a real miss in the underlying database rather than an error.
+    ///
+    /// Records `scan_duration`, `crates_scanned`, `advisories_checked`, and
+    /// `advisories_matched` against [`Telemetry`], labeled `LOG_TARGET`.
+    #[must_use]
+    pub fn scan(&self, crates: impl IntoIterator<Item = CrateSpec>) -> Vec<(CrateSpec, ProviderResult<AdvisoryData>)> {
+        self.telemetry.time("scan_duration", LOG_TARGET, || {
+            let mut crates_scanned = 0u64;
+            let mut advisories_checked = 0u64;
+            let mut advisories_matched = 0u64;
+
+            let results = crates
+                .into_iter()
+                .map(|crate_spec| {
+                    crates_scanned += 1;
+                    let mut data = AdvisoryData::default();
+                    for indexed in self.index.get(crate_spec.name()) {
+                        advisories_checked += 1;
+                        let Ok(id) = indexed.id.parse::<rustsec::advisory::Id>() else {
+                            continue;
+                        };
+                        let Some(advisory) = self.database.get(&id) else {
+                            continue;
+                        };
+                        advisories_matched += 1;
+                        data.count_advisory_historical(advisory, &[], None, false, None);
+                        data.count_advisory_for_version(advisory, crate_spec.version(), &[], None, false);
+                    }
+                    data.finalize_patch_responsiveness();
+                    (crate_spec, ProviderResult::Found(data))
+                })
+                .collect();
+
+            self.telemetry.increment("crates_scanned", LOG_TARGET, crates_scanned);
+            self.telemetry.increment("advisories_checked", LOG_TARGET, advisories_checked);
+            self.telemetry.increment("advisories_matched", LOG_TARGET, advisories_matched);
+
+            results
+        })
+    }
+}
@@ -0,0 +1,81 @@
+//! Staleness tracking for the advisory database's local git checkout, persisted as
+//! `last_synced.json` next to the checkout.
+//!
+//! [`super::Provider::new`] is the caller: it checks [`needs_fetch`] before opening the
+//! database, re-syncing with [`crate::misc::git::sync_partial_clone`] and recording a fresh
+//! [`SyncState`] on a cache miss or stale checkout, so the advisory database is refetched once
+//! it ages out instead of silently going stale forever after the first run.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Default staleness window before the advisory database is considered due for a re-sync.
+pub const DEFAULT_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+const SYNC_STATE_FILE_NAME: &str = "last_synced.json";
+
+/// When the advisory checkout was last synced, and how long that sync stays fresh for.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncState {
+    pub synced_at: DateTime<Utc>,
+    pub ttl_seconds: u64,
+}
+
+impl SyncState {
+    /// A state recording "synced just now", valid for `ttl`.
+    #[must_use]
+    pub fn new(ttl: Duration) -> Self {
+        Self { synced_at: Utc::now(), ttl_seconds: ttl.as_secs() }
+    }
+
+    /// Read `dir`'s `last_synced.json`, if present.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file exists but isn't valid JSON.
+    pub fn load(dir: &Path) -> anyhow::Result<Option<Self>> {
+        let path = Self::path(dir);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let contents = std::fs::read_to_string(&path)?;
+        Ok(Some(serde_json::from_str(&contents)?))
+    }
+
+    /// Persist `self` to `dir`'s `last_synced.json`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the write fails.
+    pub fn save(&self, dir: &Path) -> anyhow::Result<()> {
+        let contents = serde_json::to_string_pretty(self)?;
+        std::fs::write(Self::path(dir), contents)?;
+        Ok(())
+    }
+
+    /// Whether `ttl_seconds` has elapsed since `synced_at`.
+    #[must_use]
+    pub fn is_stale(&self) -> bool {
+        let ttl = chrono::Duration::seconds(i64::try_from(self.ttl_seconds).unwrap_or(i64::MAX));
+        Utc::now() - self.synced_at > ttl
+    }
+
+    fn path(dir: &Path) -> PathBuf {
+        dir.join(SYNC_STATE_FILE_NAME)
+    }
+}
+
+/// Whether the advisory checkout at `dir` needs a fresh sync: no recorded state at all, or a
+/// stale one.
+///
+/// # Errors
+///
+/// Returns an error if an existing `last_synced.json` can't be read.
+pub fn needs_fetch(dir: &Path) -> anyhow::Result<bool> {
+    Ok(match SyncState::load(dir)? {
+        Some(state) => state.is_stale(),
+        None => true,
+    })
+}
@@ -0,0 +1,60 @@
+use super::LanguageLineStats;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Static analysis of a crate's extracted source tree.
+///
+/// Complements registry-derived facts with figures that only come from walking the actual
+/// files: unsafe usage, example counts, and the per-language line breakdown below, produced
+/// the way tokei/udedokei tally code/comment/blank lines.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodebaseData {
+    /// Timestamp when this data was collected.
+    pub timestamp: DateTime<Utc>,
+
+    /// Number of `unsafe` blocks, functions, traits, and impls found in the crate's own Rust
+    /// source.
+    pub unsafe_count: u64,
+
+    /// Number of transitive dependencies resolved for this crate.
+    pub transitive_dependencies: u64,
+
+    /// Number of example programs shipped under the crate's `examples/` directory.
+    pub example_count: u64,
+
+    /// Per-language line counts across the crate's source tree, skipping generated files
+    /// (e.g. `build.rs` output, `target/`) and excluding vendored trees that carry their own
+    /// `Cargo.toml` or package manifest.
+    pub languages: Vec<LanguageLineStats>,
+}
+
+impl CodebaseData {
+    /// Total lines of code (excluding comments and blank lines) across every language.
+    #[must_use]
+    pub fn total_code_lines(&self) -> u64 {
+        self.languages.iter().map(|l| l.code_lines).sum()
+    }
+
+    /// Ratio of comment lines to commentable lines (code plus comments) across every
+    /// language, in `[0, 1]`. A crate with no code or comments is trivially `0.0`.
+    #[must_use]
+    pub fn comment_ratio(&self) -> f64 {
+        let code_lines: u64 = self.languages.iter().map(|l| l.code_lines).sum();
+        let comment_lines: u64 = self.languages.iter().map(|l| l.comment_lines).sum();
+        let commentable = code_lines + comment_lines;
+        if commentable == 0 {
+            return 0.0;
+        }
+
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for ratio calculation")]
+        let ratio = comment_lines as f64 / commentable as f64;
+        ratio
+    }
+
+    /// Total lines (code, comment, and blank) contributed by every language other than Rust,
+    /// e.g. vendored C/C++ or assembly shipped alongside the crate.
+    #[must_use]
+    pub fn non_rust_line_count(&self) -> u64 {
+        self.languages.iter().filter(|l| l.language != "Rust").map(LanguageLineStats::total_lines).sum()
+    }
+}
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+/// Line breakdown for a single programming language detected in a crate's source tree,
+/// in the spirit of tokei/udedokei's per-language tables.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LanguageLineStats {
+    /// Name of the language, e.g. `"Rust"` or `"C"`.
+    pub language: String,
+
+    /// Lines containing executable or declarative code.
+    pub code_lines: u64,
+
+    /// Lines containing only comments.
+    pub comment_lines: u64,
+
+    /// Blank lines.
+    pub blank_lines: u64,
+}
+
+impl LanguageLineStats {
+    /// Total lines of every kind for this language.
+    #[must_use]
+    pub const fn total_lines(&self) -> u64 {
+        self.code_lines + self.comment_lines + self.blank_lines
+    }
+}
@@ -0,0 +1,8 @@
+//! Static source-tree analysis fact provider, feeding unsafe-usage, example-count, and
+//! per-language line-count metrics.
+
+mod codebase_data;
+mod language_line_stats;
+
+pub use codebase_data::CodebaseData;
+pub use language_line_stats::LanguageLineStats;
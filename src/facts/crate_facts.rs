@@ -4,7 +4,11 @@ use crate::facts::codebase::CodebaseData;
 use crate::facts::coverage::CoverageData;
 use crate::facts::crates::{CrateOverallData, CrateVersionData};
 use crate::facts::docs::DocsData;
+use crate::facts::freshness::DependencyFreshnessData;
 use crate::facts::hosting::HostingData;
+use crate::facts::reviews::ReviewData;
+use crate::facts::size::SizeData;
+use crate::facts::vet::VetData;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -19,6 +23,10 @@ pub struct CrateFacts {
     pub codebase_data: ProviderResult<CodebaseData>,
     pub coverage_data: ProviderResult<CoverageData>,
     pub docs_data: ProviderResult<DocsData>,
+    pub size_data: ProviderResult<SizeData>,
+    pub vet_data: ProviderResult<VetData>,
+    pub dependency_freshness_data: ProviderResult<DependencyFreshnessData>,
+    pub review_data: ProviderResult<ReviewData>,
 }
 
 impl CrateFacts {
@@ -32,5 +40,9 @@ impl CrateFacts {
             && self.codebase_data.is_found()
             && self.coverage_data.is_found()
             && self.docs_data.is_found()
+            && self.size_data.is_found()
+            && self.vet_data.is_found()
+            && self.dependency_freshness_data.is_found()
+            && self.review_data.is_found()
     }
 }
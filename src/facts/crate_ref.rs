@@ -0,0 +1,111 @@
+use crate::facts::CrateSpec;
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use core::str::FromStr;
+use semver::{Version, VersionReq};
+use std::sync::Arc;
+
+/// Version constraint carried by a [`CrateRef`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CrateRefVersion {
+    /// No version constraint; matches every version of the named crate.
+    Any,
+
+    /// Pinned to one exact, concrete version.
+    Exact(Arc<Version>),
+
+    /// A semver requirement such as `^1.2` or `>=1,<2`, which may admit more than one
+    /// version.
+    Req(VersionReq),
+}
+
+/// A crate identifier consisting of a name and an optional version constraint, as typed on
+/// the CLI: `serde`, `serde@1.0.200`, `serde@1`, `serde@^1.2`, or `serde@>=1,<2`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CrateRef {
+    name: Arc<str>,
+    version: CrateRefVersion,
+}
+
+impl CrateRef {
+    /// Create a new crate reference with a name and version constraint.
+    #[must_use]
+    pub fn new(name: impl AsRef<str>, version: CrateRefVersion) -> Self {
+        Self {
+            name: Arc::from(name.as_ref()),
+            version,
+        }
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[must_use]
+    pub const fn version(&self) -> &CrateRefVersion {
+        &self.version
+    }
+
+    /// Get a clone of the name Arc (cheap pointer clone, no allocation).
+    #[must_use]
+    pub fn name_arc(&self) -> Arc<str> {
+        Arc::clone(&self.name)
+    }
+
+    /// Returns true if `spec` satisfies this reference: the name matches and either this
+    /// reference carries no constraint, it's pinned to the same exact version, or its
+    /// requirement admits `spec`'s version.
+    #[must_use]
+    pub fn matches(&self, spec: &CrateSpec) -> bool {
+        if self.name() != spec.name() {
+            return false;
+        }
+
+        match &self.version {
+            CrateRefVersion::Any => true,
+            CrateRefVersion::Exact(version) => version.as_ref() == spec.version(),
+            CrateRefVersion::Req(req) => req.matches(spec.version()),
+        }
+    }
+
+    /// Convert to a `CrateSpec` by cloning Arc pointers (no allocation). Only an `Exact`
+    /// reference pins to one concrete spec; `Any` and `Req` references return `None` since
+    /// either may admit more than one version.
+    #[must_use]
+    pub fn to_spec(&self) -> Option<CrateSpec> {
+        match &self.version {
+            CrateRefVersion::Exact(version) => Some(CrateSpec::from_arcs(Arc::clone(&self.name), Arc::clone(version))),
+            CrateRefVersion::Any | CrateRefVersion::Req(_) => None,
+        }
+    }
+}
+
+impl FromStr for CrateRef {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        let Some((name, constraint)) = s.split_once('@') else {
+            return Ok(Self::new(s, CrateRefVersion::Any));
+        };
+
+        if let Ok(version) = Version::parse(constraint) {
+            return Ok(Self::new(name, CrateRefVersion::Exact(Arc::new(version))));
+        }
+
+        let req = VersionReq::parse(constraint)
+            .map_err(|e| anyhow::anyhow!("parsing version requirement '{constraint}' in crate specifier '{s}': {e}"))?;
+        Ok(Self::new(name, CrateRefVersion::Req(req)))
+    }
+}
+
+impl Display for CrateRef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.name())?;
+        match &self.version {
+            CrateRefVersion::Any => {}
+            CrateRefVersion::Exact(version) => write!(f, "@{version}")?,
+            CrateRefVersion::Req(req) => write!(f, "@{req}")?,
+        }
+        Ok(())
+    }
+}
@@ -1,7 +1,9 @@
 use crate::facts::repo_spec::RepoSpec;
+use crate::misc::VersionSelection;
 use core::fmt::{Display, Formatter, Result as FmtResult};
 use semver::Version;
 use std::collections::HashMap;
+use std::collections::hash_map::Entry;
 use std::sync::Arc;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -35,6 +37,11 @@ impl CrateSpec {
         &self.name
     }
 
+    #[must_use]
+    pub fn name_arc(&self) -> &Arc<str> {
+        &self.name
+    }
+
     #[must_use]
     pub fn version(&self) -> &Version {
         &self.version
@@ -59,6 +66,41 @@ pub fn by_repo(specs: impl IntoIterator<Item = CrateSpec>) -> HashMap<Arc<RepoSp
     repo_crates
 }
 
+/// Filters `specs` down to the version(s) to appraise per crate name, per `mode`: [`Newest`] and
+/// [`Minimal`] keep the single highest/lowest [`Version`] seen for each name (ties broken by
+/// first-seen), while [`All`] passes every instance through unchanged.
+///
+/// [`Newest`]: VersionSelection::Newest
+/// [`Minimal`]: VersionSelection::Minimal
+/// [`All`]: VersionSelection::All
+#[must_use]
+pub fn select_versions(specs: impl IntoIterator<Item = CrateSpec>, mode: VersionSelection) -> Vec<CrateSpec> {
+    if mode == VersionSelection::All {
+        return specs.into_iter().collect();
+    }
+
+    let mut selected: HashMap<Arc<str>, CrateSpec> = HashMap::new();
+    for spec in specs {
+        match selected.entry(Arc::clone(spec.name_arc())) {
+            Entry::Vacant(e) => {
+                _ = e.insert(spec);
+            }
+            Entry::Occupied(mut e) => {
+                let keep_new = match mode {
+                    VersionSelection::Newest => spec.version() > e.get().version(),
+                    VersionSelection::Minimal => spec.version() < e.get().version(),
+                    VersionSelection::All => unreachable!("handled by the early return above"),
+                };
+                if keep_new {
+                    _ = e.insert(spec);
+                }
+            }
+        }
+    }
+
+    selected.into_values().collect()
+}
+
 impl Display for CrateSpec {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         write!(f, "{}@{}", self.name(), self.version())?;
@@ -84,4 +84,29 @@ pub struct CrateOverallData {
     /// 2. `versions.csv` → `versions` table (map `version_ids` back to their `crate_ids`)
     /// 3. Count unique dependent `crate_ids`
     pub dependents: u64,
+
+    /// Number of unique crates that depend on this crate as a required (non-optional)
+    /// dependency. A subset of [`Self::dependents`].
+    ///
+    /// **Source**: Same join as [`Self::dependents`], filtered to `dependencies.csv` rows
+    /// where `optional = false`.
+    pub required_dependents: u64,
+
+    /// Number of unique crates that depend on this crate only behind an optional feature
+    /// flag. A subset of [`Self::dependents`], disjoint from [`Self::required_dependents`].
+    ///
+    /// **Source**: Same join as [`Self::dependents`], filtered to `dependencies.csv` rows
+    /// where `optional = true`.
+    pub optional_dependents: u64,
+
+    /// Monthly downloads attributable to this crate's single most-downloaded direct
+    /// reverse-dependency, for the same month as the last entry of `monthly_downloads`.
+    /// Lets callers discount popularity inflation caused by one dominant consumer (e.g. an
+    /// internal derive/impl crate with exactly one heavy user).
+    ///
+    /// **Source**: Computed from multi-table join:
+    /// 1. `dependencies.csv` → `dependencies` table (find all direct dependents of this `crate_id`)
+    /// 2. `version_downloads.csv` → `version_downloads` table, aggregated by (year, month) per dependent
+    /// 3. Take the largest per-dependent monthly total for the most recent month
+    pub most_downloaded_dependent_monthly_downloads: u64,
 }
@@ -1,3 +1,4 @@
+use super::maintenance_status::MaintenanceStatus;
 use super::rust_edition::RustEdition;
 use chrono::{DateTime, Utc};
 use semver::Version;
@@ -87,4 +88,11 @@ pub struct CrateVersionData {
     ///
     /// **Source**: `versions.csv` → `versions` table → `downloads` field
     pub downloads: u64,
+
+    /// The `badges.maintenance.status` value declared in this version's `Cargo.toml`, if any.
+    /// Absent when the crate carries no maintenance badge at all.
+    ///
+    /// **Source**: `badges.csv` → `badges` table → `attributes->>'status'` field, filtered to
+    /// `badge_type = 'maintenance'`
+    pub maintenance_status: Option<MaintenanceStatus>,
 }
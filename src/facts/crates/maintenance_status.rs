@@ -0,0 +1,36 @@
+//! Maintenance-badge status type.
+
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
+
+/// The `badges.maintenance.status` value a crate version declares in its `Cargo.toml`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize, Display, EnumString)]
+pub enum MaintenanceStatus {
+    #[serde(rename = "actively-developed")]
+    #[strum(serialize = "actively-developed")]
+    ActivelyDeveloped,
+
+    #[serde(rename = "passively-maintained")]
+    #[strum(serialize = "passively-maintained")]
+    PassivelyMaintained,
+
+    #[serde(rename = "as-is")]
+    #[strum(serialize = "as-is")]
+    AsIs,
+
+    #[serde(rename = "experimental")]
+    #[strum(serialize = "experimental")]
+    Experimental,
+
+    #[serde(rename = "looking-for-maintainer")]
+    #[strum(serialize = "looking-for-maintainer")]
+    LookingForMaintainer,
+
+    #[serde(rename = "deprecated")]
+    #[strum(serialize = "deprecated")]
+    Deprecated,
+
+    #[serde(rename = "none")]
+    #[strum(serialize = "none")]
+    None,
+}
@@ -5,13 +5,18 @@
 
 mod crate_overall_data;
 mod crate_version_data;
+mod maintenance_status;
 mod owner;
 mod owner_kind;
 mod provider;
+mod reverse_deps_cache;
 mod rust_edition;
 mod tables;
 
 pub use crate_overall_data::CrateOverallData;
 pub use crate_version_data::CrateVersionData;
+pub use maintenance_status::MaintenanceStatus;
 pub use owner_kind::OwnerKind;
 pub use provider::Provider;
+pub use reverse_deps_cache::{ReverseDepsCache, ReverseDepsCount};
+pub use rust_edition::RustEdition;
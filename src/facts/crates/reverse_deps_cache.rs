@@ -0,0 +1,39 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Required-vs-optional reverse-dependency split for a single crate, as tallied from the
+/// registry index.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ReverseDepsCount {
+    pub required: u64,
+    pub optional: u64,
+}
+
+impl ReverseDepsCount {
+    /// Total reverse dependencies, required and optional combined.
+    #[must_use]
+    pub const fn total(self) -> u64 {
+        self.required + self.optional
+    }
+}
+
+/// Memoizes reverse-dependency counts keyed by crate name, so looking up the same crate
+/// repeatedly across a dependency tree (where many packages share transitive dependencies)
+/// only walks the registry index once per name.
+#[derive(Debug, Default)]
+pub struct ReverseDepsCache {
+    counts: HashMap<Arc<str>, ReverseDepsCount>,
+}
+
+impl ReverseDepsCache {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the cached count for `name`, computing and storing it via `compute` on a
+    /// cache miss.
+    pub fn get_or_compute(&mut self, name: Arc<str>, compute: impl FnOnce() -> ReverseDepsCount) -> ReverseDepsCount {
+        *self.counts.entry(name).or_insert_with(compute)
+    }
+}
@@ -0,0 +1,24 @@
+//! Rust edition type.
+
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
+
+/// The Rust edition a crate version targets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize, Display, EnumString)]
+pub enum RustEdition {
+    #[serde(rename = "2015")]
+    #[strum(serialize = "2015")]
+    Edition2015,
+
+    #[serde(rename = "2018")]
+    #[strum(serialize = "2018")]
+    Edition2018,
+
+    #[serde(rename = "2021")]
+    #[strum(serialize = "2021")]
+    Edition2021,
+
+    #[serde(rename = "2024")]
+    #[strum(serialize = "2024")]
+    Edition2024,
+}
@@ -0,0 +1,37 @@
+use super::DependencySnapshot;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Freshness standing of every direct dependency against the latest release on the registry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DependencyFreshnessData {
+    /// Timestamp when this data was collected.
+    pub timestamp: DateTime<Utc>,
+
+    /// One snapshot per direct dependency.
+    pub dependencies: Vec<DependencySnapshot>,
+}
+
+impl DependencyFreshnessData {
+    /// Mean freshness across every dependency snapshot. A crate with no dependencies is
+    /// trivially fully fresh.
+    #[must_use]
+    pub fn mean_freshness(&self) -> f64 {
+        if self.dependencies.is_empty() {
+            return 1.0;
+        }
+
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for freshness scoring")]
+        let count = self.dependencies.len() as f64;
+        self.dependencies.iter().map(DependencySnapshot::freshness).sum::<f64>() / count
+    }
+
+    /// The `n` least-fresh dependencies, sorted stalest-first, for failure messages.
+    #[must_use]
+    pub fn stalest(&self, n: usize) -> Vec<&DependencySnapshot> {
+        let mut sorted: Vec<_> = self.dependencies.iter().collect();
+        sorted.sort_by(|a, b| a.freshness().partial_cmp(&b.freshness()).unwrap_or(core::cmp::Ordering::Equal));
+        sorted.truncate(n);
+        sorted
+    }
+}
@@ -0,0 +1,51 @@
+use chrono::{DateTime, Utc};
+use semver::Version;
+use serde::{Deserialize, Serialize};
+
+/// A single direct dependency's resolved version, standing against the latest release
+/// published on the registry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DependencySnapshot {
+    /// Name of the dependency crate.
+    pub name: String,
+
+    /// Version this crate's manifest resolves to.
+    pub used_version: Version,
+
+    /// When `used_version` was published.
+    pub used_released_at: DateTime<Utc>,
+
+    /// Newest version published for this dependency on the registry.
+    pub latest_version: Version,
+
+    /// When `latest_version` was published.
+    pub latest_released_at: DateTime<Utc>,
+
+    /// Whether `used_version` has been yanked from the registry.
+    pub yanked: bool,
+
+    /// Whether `used_version` is marked deprecated in favor of a later release.
+    pub deprecated: bool,
+}
+
+impl DependencySnapshot {
+    /// Normalized freshness in `[0, 1]`: `1.0` on the newest release, decaying with the
+    /// number of major versions behind and the age gap to the newest release. Always `0.0`
+    /// for a yanked or deprecated release, regardless of how close the version is.
+    #[must_use]
+    pub fn freshness(&self) -> f64 {
+        if self.yanked || self.deprecated {
+            return 0.0;
+        }
+
+        let major_gap = self.latest_version.major.saturating_sub(self.used_version.major);
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for freshness scoring")]
+        let major_penalty = 1.0 / (1.0 + major_gap as f64);
+
+        let age_gap_days = (self.latest_released_at - self.used_released_at).num_days().max(0);
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for freshness scoring")]
+        let age_penalty = 1.0 / (1.0 + (age_gap_days as f64 / 365.0));
+
+        (major_penalty * age_penalty).clamp(0.0, 1.0)
+    }
+}
@@ -0,0 +1,7 @@
+//! Per-dependency version-freshness fact provider, feeding the `DependencyFreshness` metric.
+
+mod dependency_freshness_data;
+mod dependency_snapshot;
+
+pub use dependency_freshness_data::DependencyFreshnessData;
+pub use dependency_snapshot::DependencySnapshot;
@@ -2,8 +2,8 @@
 
 use core::sync::atomic::{AtomicBool, Ordering};
 use core::time::Duration;
-use indicatif::{ProgressBar, ProgressDrawTarget, ProgressStyle};
-use std::sync::Arc;
+use indicatif::{MultiProgress, ProgressBar, ProgressDrawTarget, ProgressStyle};
+use std::sync::{Arc, Mutex};
 use std::time::Instant;
 
 /// Shared state for delayed progress reporting.
@@ -25,6 +25,12 @@ struct DelayedProgressState {
 pub struct ProgressReporter {
     bar: ProgressBar,
     state: Arc<DelayedProgressState>,
+    /// Present only on a reporter created via [`Self::new_multi`]; lets [`Self::add_child`]
+    /// attach sibling bars that share the same terminal region instead of overwriting each other.
+    multi: Option<MultiProgress>,
+    /// Delayed child reporters handed out by [`Self::add_child`], kept around so
+    /// [`Self::finish_and_clear`] can tear every one of them down together with the parent.
+    children: Arc<Mutex<Vec<ProgressReporter>>>,
 }
 
 impl ProgressReporter {
@@ -33,7 +39,45 @@ impl ProgressReporter {
     /// The progress bar will only become visible if operations continue beyond the delay threshold.
     #[must_use]
     pub fn new(delay: Duration) -> Self {
-        // Create the progress bar with the standard style
+        let bar = Self::styled_bar(delay);
+        Self {
+            bar,
+            state: Arc::new(DelayedProgressState {
+                start_time: Instant::now(),
+                delay,
+                visible: AtomicBool::new(false),
+                has_content: AtomicBool::new(false),
+                is_indeterminate: AtomicBool::new(false),
+            }),
+            multi: None,
+            children: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Create a progress reporter that can hand out concurrent child bars via [`Self::add_child`],
+    /// e.g. one delayed bar per named request category, all drawn in the same terminal region via
+    /// indicatif's `MultiProgress` instead of being flattened into a single comma-joined message.
+    #[must_use]
+    pub fn new_multi(delay: Duration) -> Self {
+        let multi = MultiProgress::new();
+        let bar = multi.add(Self::styled_bar(delay));
+        Self {
+            bar,
+            state: Arc::new(DelayedProgressState {
+                start_time: Instant::now(),
+                delay,
+                visible: AtomicBool::new(false),
+                has_content: AtomicBool::new(false),
+                is_indeterminate: AtomicBool::new(false),
+            }),
+            multi: Some(multi),
+            children: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Build a hidden progress bar with the standard style, ready to be shown once delayed
+    /// visibility kicks in.
+    fn styled_bar(_delay: Duration) -> ProgressBar {
         let bar = ProgressBar::hidden();
         bar.set_style(
             ProgressStyle::default_bar()
@@ -43,17 +87,38 @@ impl ProgressReporter {
         );
         bar.set_length(0);
         bar.set_draw_target(ProgressDrawTarget::hidden());
+        bar
+    }
 
-        Self {
+    /// Hand out a new delayed child reporter attached to this reporter's `MultiProgress`,
+    /// labeled with `prefix` (e.g. a request category like `"GitHub"` or `"docs.rs"`).
+    ///
+    /// Returns `None` if this reporter wasn't created with [`Self::new_multi`]. The child gets
+    /// its own independent delayed-visibility timer and indeterminate/determinate state, and is
+    /// torn down along with the parent when [`Self::finish_and_clear`] is called on the parent.
+    #[must_use]
+    pub fn add_child(&self, prefix: &str) -> Option<Self> {
+        let multi = self.multi.as_ref()?;
+        let bar = multi.add(Self::styled_bar(self.state.delay));
+        let child = Self {
             bar,
             state: Arc::new(DelayedProgressState {
                 start_time: Instant::now(),
-                delay,
+                delay: self.state.delay,
                 visible: AtomicBool::new(false),
                 has_content: AtomicBool::new(false),
                 is_indeterminate: AtomicBool::new(false),
             }),
-        }
+            multi: None,
+            children: Arc::new(Mutex::new(Vec::new())),
+        };
+        child.set_prefix(prefix);
+
+        let mut children = self.children.lock().expect("lock poisoned");
+        children.push(child.clone());
+        drop(children);
+
+        Some(child)
     }
 
     /// Check if enough time has elapsed and we have content, then make the progress bar visible if needed.
@@ -238,8 +303,26 @@ impl ProgressReporter {
         self.bar.enable_steady_tick(Duration::from_millis(100));
     }
 
-    /// Finish and clear the progress indicator.
+    /// Finish and clear the progress indicator, along with every child handed out by
+    /// [`Self::add_child`], so nothing lingers on stderr once the parent goes away.
     pub fn finish_and_clear(&self) {
+        let children = self.children.lock().expect("lock poisoned");
+        for child in children.iter() {
+            child.finish_and_clear_self();
+        }
+        drop(children);
+
+        self.finish_and_clear_self();
+
+        // Belt-and-braces: drop the whole multi region in case a child became visible
+        // concurrently with this call, after it was already iterated above.
+        if let Some(multi) = &self.multi {
+            let _ = multi.clear();
+        }
+    }
+
+    /// Finish and clear just this reporter's own bar, without touching any children.
+    fn finish_and_clear_self(&self) {
         // Only finish and clear if the bar was actually made visible
         if self.state.visible.load(Ordering::Relaxed) {
             self.bar.finish_and_clear();
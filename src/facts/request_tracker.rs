@@ -1,15 +1,27 @@
 //! Request tracking for monitoring outstanding HTTP requests.
 
 use crate::facts::progress_reporter::ProgressReporter;
+use crate::misc::RequestLogging;
 use core::sync::atomic::{AtomicU64, Ordering};
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use tracing::Span;
 
 /// Counter for a specific named request type.
 #[derive(Debug, Default)]
 struct RequestCounter {
     issued: AtomicU64,
     completed: AtomicU64,
+    failed: AtomicU64,
+    retrying: AtomicU64,
+}
+
+/// The span and start time of a single in-flight request, keyed by category name and URL so
+/// `complete_request`/`fail_request` can look it back up and log its elapsed duration.
+struct InFlightRequest {
+    span: Span,
+    issued_at: Instant,
 }
 
 /// Tracks outstanding requests and updates progress reporting.
@@ -18,20 +30,44 @@ struct RequestCounter {
 /// and codecov.io, providing visibility into the query phase of crate analysis.
 ///
 /// Requests are tracked by name, allowing separate counters for different request types
-/// (e.g., "GitHub", "docs.rs", "codecov.io").
+/// (e.g., "GitHub", "docs.rs", "codecov.io"). Each request tracked by URL also gets a `tracing`
+/// span covering its issue-to-completion lifetime; [`Self`]'s configured [`RequestLogging`]
+/// level decides which events are logged on that span, so a timing breakdown of slow or failed
+/// requests can be captured without cluttering interactive output.
+///
+/// Transient failures can be retried with [`crate::facts::retry::retry_with_backoff`], which
+/// brackets each backoff wait with [`Self::begin_retry`]/[`Self::end_retry`] so the progress
+/// message surfaces pending retries (e.g. `3/10 GitHub (1 retrying)`) before a request is
+/// finally marked completed or failed.
 #[derive(Debug, Clone)]
 pub struct RequestTracker {
     counters: Arc<Mutex<HashMap<String, Arc<RequestCounter>>>>,
+    in_flight: Arc<Mutex<HashMap<(String, String), InFlightRequest>>>,
     progress: ProgressReporter,
+    /// One delayed child bar per named category, lazily created from `progress` the first time
+    /// that category is seen. Stays empty (and every category falls back to the comma-joined
+    /// message on `progress` itself) unless `progress` was built with [`ProgressReporter::new_multi`].
+    category_bars: Arc<Mutex<HashMap<String, ProgressReporter>>>,
+    request_logging: RequestLogging,
+}
+
+impl core::fmt::Debug for InFlightRequest {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("InFlightRequest").field("issued_at", &self.issued_at).finish_non_exhaustive()
+    }
 }
 
 impl RequestTracker {
-    /// Create a new request tracker with the given progress reporter.
+    /// Create a new request tracker with the given progress reporter and request-logging
+    /// verbosity (see [`RequestLogging`]).
     #[must_use]
-    pub fn new(progress: ProgressReporter) -> Self {
+    pub fn new(progress: ProgressReporter, request_logging: RequestLogging) -> Self {
         Self {
             counters: Arc::new(Mutex::new(HashMap::new())),
+            in_flight: Arc::new(Mutex::new(HashMap::new())),
             progress,
+            category_bars: Arc::new(Mutex::new(HashMap::new())),
+            request_logging,
         }
     }
 
@@ -45,14 +81,31 @@ impl RequestTracker {
         )
     }
 
-    /// Mark that a new request has been issued for the given named category.
-    pub fn add_request(&self, name: &str) {
+    /// Mark that a new request has been issued for the given named category and URL.
+    ///
+    /// Opens a `tracing` span covering the request's lifetime; at [`RequestLogging::All`] this
+    /// also logs an "issued" event immediately.
+    pub fn add_request(&self, name: &str, url: &str) {
         let counter = self.get_counter(name);
         let _ = counter.issued.fetch_add(1, Ordering::Relaxed);
+
+        let span = tracing::info_span!("request", category = %name, url = %url);
+        if self.request_logging == RequestLogging::All {
+            let _entered = span.enter();
+            tracing::info!("issued");
+        }
+
+        let mut in_flight = self.in_flight.lock().expect("lock poisoned");
+        let _ = in_flight.insert((name.to_string(), url.to_string()), InFlightRequest { span, issued_at: Instant::now() });
+        drop(in_flight);
+
         self.update_progress();
     }
 
     /// Mark that multiple new requests have been issued for the given named category.
+    ///
+    /// Used for batch operations where individual URLs aren't tracked; no per-request span is
+    /// created.
     pub fn add_many_requests(&self, name: &str, count: u64) {
         if count == 0 {
             return;
@@ -62,20 +115,104 @@ impl RequestTracker {
         self.update_progress();
     }
 
-    /// Mark that a request has completed for the given named category.
-    pub fn complete_request(&self, name: &str) {
+    /// Mark that the request for the given named category and URL completed successfully.
+    ///
+    /// At [`RequestLogging::CompletedOnly`] or [`RequestLogging::All`], logs a "completed" event
+    /// on the request's span with its elapsed duration.
+    pub fn complete_request(&self, name: &str, url: &str) {
         let counter = self.get_counter(name);
         let _ = counter.completed.fetch_add(1, Ordering::Relaxed);
+
+        if let Some(in_flight) = self.take_in_flight(name, url) {
+            if self.request_logging != RequestLogging::Off {
+                let _entered = in_flight.span.enter();
+                tracing::info!(elapsed_ms = in_flight.issued_at.elapsed().as_millis(), "completed");
+            }
+        }
+
         self.update_progress();
     }
 
-    /// Update progress reporter with current request counts across all categories.
+    /// Mark that the request for the given named category and URL failed with `reason`.
+    ///
+    /// At [`RequestLogging::CompletedOnly`] or [`RequestLogging::All`], logs a "failed" event on
+    /// the request's span with its elapsed duration and `reason`.
+    pub fn fail_request(&self, name: &str, url: &str, reason: &str) {
+        let counter = self.get_counter(name);
+        let _ = counter.failed.fetch_add(1, Ordering::Relaxed);
+
+        if let Some(in_flight) = self.take_in_flight(name, url) {
+            if self.request_logging != RequestLogging::Off {
+                let _entered = in_flight.span.enter();
+                tracing::warn!(elapsed_ms = in_flight.issued_at.elapsed().as_millis(), reason, "failed");
+            }
+        }
+
+        self.update_progress();
+    }
+
+    /// Remove and return the in-flight span/start-time for `(name, url)`, if one was recorded
+    /// by [`Self::add_request`].
+    fn take_in_flight(&self, name: &str, url: &str) -> Option<InFlightRequest> {
+        let mut in_flight = self.in_flight.lock().expect("lock poisoned");
+        in_flight.remove(&(name.to_string(), url.to_string()))
+    }
+
+    /// Mark that the request for the given named category and URL is backing off before a retry
+    /// attempt, after a retryable failure.
+    ///
+    /// Increments the "retrying" gauge shown in the progress message until the matching
+    /// [`Self::end_retry`] call. At [`RequestLogging::CompletedOnly`] or [`RequestLogging::All`],
+    /// logs a "retrying" event on the request's span with the attempt number and backoff delay.
+    pub fn begin_retry(&self, name: &str, url: &str, attempt: u32, delay: core::time::Duration) {
+        let counter = self.get_counter(name);
+        let _ = counter.retrying.fetch_add(1, Ordering::Relaxed);
+
+        if self.request_logging != RequestLogging::Off {
+            let in_flight = self.in_flight.lock().expect("lock poisoned");
+            if let Some(in_flight) = in_flight.get(&(name.to_string(), url.to_string())) {
+                let _entered = in_flight.span.enter();
+                tracing::warn!(attempt, delay_ms = delay.as_millis(), "retrying");
+            }
+        }
+
+        self.update_progress();
+    }
+
+    /// Mark that the request for the given named category and URL has finished backing off and
+    /// is being attempted again, undoing the effect of [`Self::begin_retry`].
+    pub fn end_retry(&self, name: &str, url: &str) {
+        let _ = url;
+        let counter = self.get_counter(name);
+        let _ = counter.retrying.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |r| Some(r.saturating_sub(1)));
+        self.update_progress();
+    }
+
+    /// Get or lazily create the delayed child bar for `name`, if `self.progress` supports
+    /// handing out children (i.e. was built with [`ProgressReporter::new_multi`]).
+    fn category_bar(&self, name: &str) -> Option<ProgressReporter> {
+        let mut bars = self.category_bars.lock().expect("lock poisoned");
+        if let Some(bar) = bars.get(name) {
+            return Some(bar.clone());
+        }
+        let bar = self.progress.add_child(name)?;
+        let _ = bars.insert(name.to_string(), bar.clone());
+        Some(bar)
+    }
+
+    /// Update progress reporting with current request counts across all categories.
+    ///
+    /// When `self.progress` owns a `MultiProgress` (see [`ProgressReporter::new_multi`]), each
+    /// category gets its own delayed bar via [`Self::category_bar`], so e.g. a stalled docs.rs
+    /// bar is visible independently of GitHub racing ahead; the parent bar then just tracks the
+    /// combined total. Otherwise every category is flattened into one comma-joined message on
+    /// the parent bar, as before.
     fn update_progress(&self) {
         let counters = self.counters.lock().expect("lock poisoned");
 
         // Calculate totals
         let mut total_issued = 0u64;
-        let mut total_completed = 0u64;
+        let mut total_resolved = 0u64;
         let mut parts = Vec::new();
 
         // Collect stats for each named category, sorted by name for consistent ordering
@@ -86,22 +223,45 @@ impl RequestTracker {
             if let Some(counter) = counters.get(name.as_str()) {
                 let issued = counter.issued.load(Ordering::Relaxed);
                 let completed = counter.completed.load(Ordering::Relaxed);
+                let failed = counter.failed.load(Ordering::Relaxed);
+                let retrying = counter.retrying.load(Ordering::Relaxed);
 
                 if issued > 0 {
+                    let resolved = completed + failed;
                     total_issued += issued;
-                    total_completed += completed;
-                    parts.push(format!("{completed}/{issued} {name}"));
+                    total_resolved += resolved;
+
+                    let mut suffixes = Vec::new();
+                    if retrying > 0 {
+                        suffixes.push(format!("{retrying} retrying"));
+                    }
+                    if failed > 0 {
+                        suffixes.push(format!("{failed} failed"));
+                    }
+                    let suffix = if suffixes.is_empty() { String::new() } else { format!(" ({})", suffixes.join(", ")) };
+
+                    if let Some(bar) = self.category_bar(name) {
+                        bar.enable_determinate_mode(issued);
+                        bar.set_position(resolved);
+                        bar.set_message(format!("{completed}/{issued}{suffix}"));
+                    } else {
+                        parts.push(format!("{completed}/{issued} {name}{suffix}"));
+                    }
                 }
             }
         }
 
-        // Update progress bar
+        // Update the parent progress bar
         if total_issued > 0 {
             self.progress.enable_determinate_mode(total_issued);
-            self.progress.set_position(total_completed);
+            self.progress.set_position(total_resolved);
 
-            // Format message as "X/Y name1, X/Y name2, ..."
-            let message = if parts.is_empty() {
+            // Per-category bars already carry the detail; the parent just tracks the total.
+            // Without per-category bars, format the combined message as
+            // "X/Y name1 (Z retrying, W failed), X/Y name2, ..." instead.
+            let message = if !self.category_bars.lock().expect("lock poisoned").is_empty() {
+                format!("{total_resolved}/{total_issued} total")
+            } else if parts.is_empty() {
                 "No requests".to_string()
             } else {
                 parts.join(", ")
@@ -0,0 +1,98 @@
+//! Bounded exponential backoff with jitter and `Retry-After` handling for outgoing requests.
+//!
+//! Wraps a fallible, retryable operation (a GitHub/docs.rs/codecov.io call, or any future
+//! crates.io source) so transient 429/503 responses don't fail the appraisal outright.
+//! [`RequestTracker`] is kept in the loop throughout: it sees the request issued once, any
+//! retry attempts as they happen, and exactly one terminal completion or failure.
+
+use crate::facts::request_tracker::RequestTracker;
+use core::time::Duration;
+use rand::Rng;
+
+/// Bounded exponential backoff policy: doubles the delay each attempt, capped at `max_delay`,
+/// with +/-20% jitter to avoid thundering-herd retries against the same upstream service.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    /// Total attempts allowed, including the first. A value of `1` disables retrying.
+    pub max_attempts: u32,
+    /// Delay before the first retry, before jitter.
+    pub base_delay: Duration,
+    /// Ceiling applied to both the computed backoff and an honored `Retry-After` hint.
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self { max_attempts: 5, base_delay: Duration::from_millis(500), max_delay: Duration::from_secs(30) }
+    }
+}
+
+impl RetryPolicy {
+    /// Delay to wait before `attempt` (1-based: the delay before the *second* overall try).
+    ///
+    /// Honors `retry_after` verbatim (capped at `max_delay`) when the failure carried one;
+    /// otherwise backs off exponentially from `base_delay` with +/-20% jitter.
+    #[must_use]
+    pub fn delay_for_attempt(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        if let Some(retry_after) = retry_after {
+            return retry_after.min(self.max_delay);
+        }
+
+        let exponent = attempt.saturating_sub(1).min(16);
+        let unjittered = self.base_delay.saturating_mul(1u32 << exponent).min(self.max_delay);
+        let jitter = rand::thread_rng().gen_range(0.8..1.2);
+        unjittered.mul_f64(jitter).min(self.max_delay)
+    }
+}
+
+/// A request failure as reported by the caller's operation closure.
+#[derive(Debug, Clone)]
+pub struct RetryableFailure {
+    /// Whether another attempt is worth making (e.g. a 429/503/connection reset), as opposed to
+    /// a terminal failure like a 404 or malformed response.
+    pub retryable: bool,
+    /// The upstream's `Retry-After` hint, if it sent one.
+    pub retry_after: Option<Duration>,
+    /// Human-readable reason, passed to [`RequestTracker::fail_request`] on final failure.
+    pub reason: String,
+}
+
+/// Run `operation` against `tracker`'s `(name, url)` request, retrying per `policy` on
+/// retryable failures and reporting the outcome to `tracker` throughout.
+///
+/// `tracker.add_request` must already have been called for `(name, url)`; this function calls
+/// exactly one of [`RequestTracker::complete_request`] or [`RequestTracker::fail_request`] when
+/// it returns, and brackets each backoff wait with [`RequestTracker::begin_retry`] /
+/// [`RequestTracker::end_retry`] so the progress message reflects pending retries.
+pub async fn retry_with_backoff<T, F, Fut>(
+    tracker: &RequestTracker,
+    name: &str,
+    url: &str,
+    policy: RetryPolicy,
+    mut operation: F,
+) -> Result<T, String>
+where
+    F: FnMut() -> Fut,
+    Fut: core::future::Future<Output = Result<T, RetryableFailure>>,
+{
+    let mut attempt = 1;
+    loop {
+        match operation().await {
+            Ok(value) => {
+                tracker.complete_request(name, url);
+                return Ok(value);
+            }
+            Err(failure) if failure.retryable && attempt < policy.max_attempts => {
+                let delay = policy.delay_for_attempt(attempt, failure.retry_after);
+                tracker.begin_retry(name, url, attempt, delay);
+                tokio::time::sleep(delay).await;
+                tracker.end_retry(name, url);
+                attempt += 1;
+            }
+            Err(failure) => {
+                tracker.fail_request(name, url, &failure.reason);
+                return Err(failure.reason);
+            }
+        }
+    }
+}
@@ -0,0 +1,17 @@
+//! cargo-crev web-of-trust review fact provider.
+//!
+//! Computes a trust set by flooding a cargo-crev trust-proof graph outward from
+//! user-configured root identities, then aggregates review proofs authored by identities in
+//! that set into a per-crate [`ReviewData`] summary for metric extraction.
+
+mod review_data;
+mod review_proof;
+mod trust_level;
+mod trust_proof;
+mod trust_set;
+
+pub use review_data::ReviewData;
+pub use review_proof::{ReviewProof, ReviewRating};
+pub use trust_level::TrustLevel;
+pub use trust_proof::TrustProof;
+pub use trust_set::TrustSet;
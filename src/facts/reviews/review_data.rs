@@ -0,0 +1,46 @@
+use super::{ReviewProof, ReviewRating, TrustSet};
+use semver::Version;
+use serde::{Deserialize, Serialize};
+
+/// Aggregated cargo-crev review signal for a single crate (version), computed only from
+/// proofs authored by an identity in the caller's [`TrustSet`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReviewData {
+    pub trusted_review_count: u32,
+    pub positive_review_count: u32,
+    pub negative_review_count: u32,
+    pub average_thoroughness: f64,
+    pub average_understanding: f64,
+}
+
+impl ReviewData {
+    /// Aggregates every `proofs` entry for `package` (optionally narrowed to `version`)
+    /// authored by a trusted, non-self identity.
+    #[must_use]
+    pub fn aggregate(package: &str, version: Option<&Version>, trust_set: &TrustSet, proofs: &[ReviewProof]) -> Self {
+        let relevant: Vec<&ReviewProof> = proofs
+            .iter()
+            .filter(|p| p.package == package)
+            .filter(|p| version.is_none_or(|v| p.version.as_ref() == Some(v)))
+            .filter(|p| !p.is_self_review)
+            .filter(|p| trust_set.is_trusted(&p.author))
+            .collect();
+
+        if relevant.is_empty() {
+            return Self::default();
+        }
+
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        let count = relevant.len() as f64;
+
+        Self {
+            trusted_review_count: u32::try_from(relevant.len()).unwrap_or(u32::MAX),
+            positive_review_count: u32::try_from(relevant.iter().filter(|p| p.rating == ReviewRating::Positive).count())
+                .unwrap_or(u32::MAX),
+            negative_review_count: u32::try_from(relevant.iter().filter(|p| p.rating == ReviewRating::Negative).count())
+                .unwrap_or(u32::MAX),
+            average_thoroughness: relevant.iter().map(|p| f64::from(p.thoroughness)).sum::<f64>() / count,
+            average_understanding: relevant.iter().map(|p| f64::from(p.understanding)).sum::<f64>() / count,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use semver::Version;
+use serde::{Deserialize, Serialize};
+
+/// Overall rating a cargo-crev review proof assigns to a package.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewRating {
+    Positive,
+    Neutral,
+    Negative,
+}
+
+/// A single cargo-crev review proof for a package, optionally pinned to one version.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewProof {
+    pub author: String,
+    pub package: String,
+    pub version: Option<Version>,
+    pub rating: ReviewRating,
+
+    /// How thoroughly the reviewer examined the source, `0` (none) to `2` (high).
+    pub thoroughness: u8,
+    /// How well the reviewer understood the code, `0` (none) to `2` (high).
+    pub understanding: u8,
+
+    /// `true` if `author` is also a registered owner of the reviewed package, so aggregation
+    /// can exclude it as a self-review.
+    pub is_self_review: bool,
+}
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+/// A cargo-crev trust level, as declared in a trust proof.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrustLevel {
+    Distrust,
+    None,
+    Low,
+    Medium,
+    High,
+}
+
+impl TrustLevel {
+    /// The distance cost this level contributes when flooding the trust graph, or `None` if
+    /// this level carries no trust distance: an explicit `None` claim contributes nothing,
+    /// and `Distrust` is handled separately as an exclusion rather than a distance.
+    #[must_use]
+    pub const fn distance_cost(self) -> Option<u32> {
+        match self {
+            Self::High => Some(0),
+            Self::Medium => Some(1),
+            Self::Low => Some(2),
+            Self::None | Self::Distrust => None,
+        }
+    }
+}
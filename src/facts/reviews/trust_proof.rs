@@ -0,0 +1,10 @@
+use super::TrustLevel;
+use serde::{Deserialize, Serialize};
+
+/// A single cargo-crev trust proof: `from` vouches for (or distrusts) `to` at `level`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrustProof {
+    pub from: String,
+    pub to: String,
+    pub level: TrustLevel,
+}
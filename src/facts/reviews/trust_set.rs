@@ -0,0 +1,62 @@
+use super::{TrustLevel, TrustProof};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+/// The set of identities trusted by a configured group of root identities, computed by
+/// flooding a cargo-crev trust-proof graph outward from the roots.
+#[derive(Debug, Clone, Default)]
+pub struct TrustSet {
+    trusted: HashSet<String>,
+}
+
+impl TrustSet {
+    /// Runs a Dijkstra-style flood from `roots` over `proofs`: an identity's distance is the
+    /// minimum over incoming trust edges of `source distance + edge cost`, and it's trusted
+    /// if that distance is at most `max_distance`. A `Distrust` edge from any trusted identity
+    /// excludes its target, overriding any other path that would otherwise trust it.
+    #[must_use]
+    pub fn compute(roots: &[String], proofs: &[TrustProof], max_distance: u32) -> Self {
+        let mut distances: HashMap<&str, u32> = HashMap::new();
+        let mut heap: BinaryHeap<Reverse<(u32, &str)>> = BinaryHeap::new();
+
+        for root in roots {
+            if distances.insert(root, 0).is_none() {
+                heap.push(Reverse((0, root.as_str())));
+            }
+        }
+
+        while let Some(Reverse((distance, node))) = heap.pop() {
+            if distances.get(node).is_some_and(|&best| best < distance) {
+                continue;
+            }
+
+            for proof in proofs.iter().filter(|p| p.from == node) {
+                let Some(cost) = proof.level.distance_cost() else { continue };
+                let candidate = distance + cost;
+                if distances.get(proof.to.as_str()).is_none_or(|&best| candidate < best) {
+                    _ = distances.insert(proof.to.as_str(), candidate);
+                    heap.push(Reverse((candidate, proof.to.as_str())));
+                }
+            }
+        }
+
+        let trusted_by_distance: HashSet<&str> =
+            distances.iter().filter(|&(_, &d)| d <= max_distance).map(|(&id, _)| id).collect();
+
+        let excluded: HashSet<&str> = proofs
+            .iter()
+            .filter(|p| matches!(p.level, TrustLevel::Distrust) && trusted_by_distance.contains(p.from.as_str()))
+            .map(|p| p.to.as_str())
+            .collect();
+
+        Self {
+            trusted: trusted_by_distance.difference(&excluded).map(|&s| s.to_string()).collect(),
+        }
+    }
+
+    /// Returns `true` if `id` is in the computed trust set.
+    #[must_use]
+    pub fn is_trusted(&self, id: &str) -> bool {
+        self.trusted.contains(id)
+    }
+}
@@ -0,0 +1,5 @@
+//! Crate size and dependency-weight fact provider, feeding the `Cost` metric category.
+
+mod size_data;
+
+pub use size_data::SizeData;
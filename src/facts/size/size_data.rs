@@ -0,0 +1,33 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Compile and footprint cost of depending on a crate.
+///
+/// Complements `CrateVersionData`/`CrateOverallData` with figures that matter for build
+/// times and binary size rather than popularity or trust. Derived from the crates.io
+/// database dump plus the resolved feature-dependency map in `CrateVersionData::features`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SizeData {
+    /// Timestamp when this data was collected.
+    pub timestamp: DateTime<Utc>,
+
+    /// Size in bytes of the published `.crate` tarball.
+    pub tarball_bytes: u64,
+
+    /// Uncompressed size in bytes of the crate's source files.
+    pub uncompressed_bytes: u64,
+
+    /// Number of dependencies declared directly in this version's manifest.
+    pub direct_dependency_count: u32,
+
+    /// Number of dependencies pulled in transitively, including direct ones.
+    pub transitive_dependency_count: u32,
+
+    /// Estimated total size in bytes of this crate plus its dependency closure with
+    /// default features disabled (the "minimal" build).
+    pub minimal_dependency_bytes: u64,
+
+    /// Estimated total size in bytes of this crate plus its dependency closure with
+    /// default features enabled (the "typical" build).
+    pub typical_dependency_bytes: u64,
+}
@@ -0,0 +1,29 @@
+use semver::Version;
+use serde::{Deserialize, Serialize};
+
+/// A single cargo-vet audit record for one package, as found in `audits.toml` or a trusted
+/// `imports.toml` entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// Exact version this entry certifies, for a full audit.
+    pub version: Option<Version>,
+
+    /// For a delta audit, the version this entry certifies a diff *from*. `version` holds
+    /// the "to" side of the diff.
+    pub delta_from: Option<Version>,
+
+    /// Criteria this audit attests to, e.g. `["safe-to-deploy"]`.
+    pub criteria: Vec<String>,
+
+    /// `true` if this entry came from a trusted foreign `imports.toml` source rather than
+    /// the crate's own `audits.toml`.
+    pub imported: bool,
+}
+
+impl AuditEntry {
+    /// Returns `true` if this is a full (non-delta) audit of `version`.
+    #[must_use]
+    pub fn certifies(&self, version: &Version) -> bool {
+        self.delta_from.is_none() && self.version.as_ref() == Some(version)
+    }
+}
@@ -0,0 +1,86 @@
+use super::{AuditEntry, CriteriaGraph};
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashSet, VecDeque};
+
+/// Result of searching the audit graph for a path from a trusted root to the target version.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuditPath {
+    /// A trusted root reaches the target version through full and/or delta audits that
+    /// together cover every required criterion.
+    Certified,
+
+    /// No such path exists. Names the closest version below the target that *is* reachable,
+    /// if any, so a failure message can point at the missing delta (e.g. "0.4.2 audited;
+    /// 0.5.1 under review needs a delta audit").
+    Uncertified { nearest_audited: Option<Version> },
+
+    /// A violation entry names this exact version, overriding any reachable path.
+    Violated,
+}
+
+/// In-memory cargo-vet audit graph for a single crate: every audit entry (full audits serve
+/// as trusted roots, delta audits as edges between versions), the criteria-implication
+/// graph, and any recorded violations.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AuditStore {
+    pub entries: Vec<AuditEntry>,
+    pub criteria: CriteriaGraph,
+    pub violations: HashSet<Version>,
+}
+
+impl AuditStore {
+    /// Determines whether `target` is covered for every criterion in `required`, by
+    /// breadth-first search from every full audit (a trusted root) through delta audits.
+    ///
+    /// A violation entry for `target` forces [`AuditPath::Violated`] regardless of any
+    /// otherwise-reachable path.
+    #[must_use]
+    pub fn path_to(&self, target: &Version, required: &[String]) -> AuditPath {
+        if self.violations.contains(target) {
+            return AuditPath::Violated;
+        }
+
+        let required_closure: HashSet<String> = required.iter().flat_map(|c| self.criteria.closure(c)).collect();
+
+        let mut visited: HashSet<Version> = HashSet::new();
+        let mut queue: VecDeque<Version> = VecDeque::new();
+
+        for entry in &self.entries {
+            if entry.delta_from.is_none()
+                && let Some(version) = &entry.version
+                && self.entry_satisfies(entry, &required_closure)
+                && visited.insert(version.clone())
+            {
+                queue.push_back(version.clone());
+            }
+        }
+
+        while let Some(version) = queue.pop_front() {
+            if &version == target {
+                return AuditPath::Certified;
+            }
+
+            for entry in &self.entries {
+                if entry.delta_from.as_ref() == Some(&version)
+                    && self.entry_satisfies(entry, &required_closure)
+                    && let Some(next) = &entry.version
+                    && visited.insert(next.clone())
+                {
+                    queue.push_back(next.clone());
+                }
+            }
+        }
+
+        AuditPath::Uncertified {
+            nearest_audited: visited.into_iter().filter(|version| version < target).max(),
+        }
+    }
+
+    /// Returns `true` if `entry`'s criteria, resolved through the implication closure, cover
+    /// every criterion in `required_closure`.
+    fn entry_satisfies(&self, entry: &AuditEntry, required_closure: &HashSet<String>) -> bool {
+        let entry_closure: HashSet<String> = entry.criteria.iter().flat_map(|c| self.criteria.closure(c)).collect();
+        required_closure.iter().all(|c| entry_closure.contains(c))
+    }
+}
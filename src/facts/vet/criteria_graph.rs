@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// Maps each cargo-vet criterion to the set of criteria it implies, e.g. `safe-to-deploy`
+/// implying `safe-to-run`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CriteriaGraph {
+    implies: HashMap<String, Vec<String>>,
+}
+
+impl CriteriaGraph {
+    #[must_use]
+    pub fn new(implies: HashMap<String, Vec<String>>) -> Self {
+        Self { implies }
+    }
+
+    /// Resolves the full closure of criteria implied by `criterion`, including itself.
+    #[must_use]
+    pub fn closure(&self, criterion: &str) -> HashSet<String> {
+        let mut seen = HashSet::new();
+        self.collect_closure(criterion, &mut seen);
+        seen
+    }
+
+    /// Recursively follows `implies` edges, guarding against cycles via `seen`.
+    fn collect_closure(&self, criterion: &str, seen: &mut HashSet<String>) {
+        if !seen.insert(criterion.to_string()) {
+            return;
+        }
+
+        if let Some(implied) = self.implies.get(criterion) {
+            for next in implied {
+                self.collect_closure(next, seen);
+            }
+        }
+    }
+}
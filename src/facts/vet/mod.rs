@@ -0,0 +1,15 @@
+//! cargo-vet supply-chain audit fact provider.
+//!
+//! Ingests audit records in the cargo-vet `audits.toml`/`imports.toml` format and resolves
+//! which criteria (`safe-to-deploy`, `safe-to-run`, or user-defined) a specific crate
+//! version is covered for, following the implied-criteria closure.
+
+mod audit_entry;
+mod audit_store;
+mod criteria_graph;
+mod vet_data;
+
+pub use audit_entry::AuditEntry;
+pub use audit_store::{AuditPath, AuditStore};
+pub use criteria_graph::CriteriaGraph;
+pub use vet_data::VetData;
@@ -0,0 +1,26 @@
+use super::{AuditPath, AuditStore};
+use chrono::{DateTime, Utc};
+use semver::Version;
+use serde::{Deserialize, Serialize};
+
+/// cargo-vet supply-chain audit graph for a crate, anchored at the version under analysis.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VetData {
+    pub timestamp: DateTime<Utc>,
+    pub version: Version,
+    pub store: AuditStore,
+}
+
+impl VetData {
+    #[must_use]
+    pub const fn new(timestamp: DateTime<Utc>, version: Version, store: AuditStore) -> Self {
+        Self { timestamp, version, store }
+    }
+
+    /// Searches the audit graph for a path from a trusted root to this crate's version that
+    /// covers every criterion in `required`.
+    #[must_use]
+    pub fn coverage(&self, required: &[String]) -> AuditPath {
+        self.store.path_to(&self.version, required)
+    }
+}
@@ -0,0 +1,11 @@
+//! Persistent history of past ranking runs, enabling "what regressed since last run" reports.
+//!
+//! Gated behind the `sqlite-history` feature since it pulls in `rusqlite`; nothing else in this
+//! crate depends on it being enabled.
+
+mod regression;
+mod schema;
+mod store;
+
+pub use regression::{Regression, diff};
+pub use store::{HistoryStore, StoredOutcome};
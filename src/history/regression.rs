@@ -0,0 +1,65 @@
+//! Diffing a [`StoredOutcome`] against a freshly computed [`RankingOutcome`] to surface
+//! regressions between two `cargo aprz` invocations.
+
+use crate::facts::CrateFacts;
+use crate::history::store::StoredOutcome;
+use crate::metrics::MetricCategory;
+use crate::ranking::{PolicyOutcome, RankingOutcome};
+use std::collections::{HashMap, HashSet};
+
+/// A single regression surfaced by [`diff`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Regression {
+    /// A metric (built-in or `custom_metrics` script), named by its `Display`/`ScriptPolicy`
+    /// name, that used to `Match` or `Warning` and now `NoMatch`es.
+    MetricFlipped { name: String, previous_reason: String, reason: String },
+
+    /// A [`MetricCategory`]'s average score dropped by more than the configured threshold.
+    CategoryScoreDropped { category: MetricCategory, previous: f64, current: f64 },
+
+    /// An advisory affecting the crate that wasn't recorded in the previous run.
+    NewAdvisory { rustsec_id: String },
+}
+
+/// Compare `previous` against the freshly computed `current`/`facts`, flagging:
+/// - any metric that flipped from `Match`/`Warning` to `NoMatch`
+/// - any [`MetricCategory`] whose average score dropped by more than `category_score_threshold`
+///   points (a plain difference, not a percentage — `5.0` flags a drop from `80.0` to `74.0`)
+/// - any advisory in `facts.advisory_data` that wasn't present in `previous`
+#[must_use]
+pub fn diff(previous: &StoredOutcome, current: &RankingOutcome, facts: &CrateFacts, category_score_threshold: f64) -> Vec<Regression> {
+    let mut regressions = Vec::new();
+
+    let mut current_details: HashMap<String, PolicyOutcome> = current.details.iter().map(|(metric, o)| (metric.to_string(), o.clone())).collect();
+    current_details.extend(current.custom_details.iter().map(|(name, o)| (name.clone(), o.clone())));
+
+    for (name, previous_outcome) in &previous.details {
+        let previous_reason = match previous_outcome {
+            PolicyOutcome::Match(_, info) | PolicyOutcome::Warning(_, info) => info.clone(),
+            PolicyOutcome::NoMatch(_) => continue,
+        };
+
+        if let Some(PolicyOutcome::NoMatch(reason)) = current_details.get(name) {
+            regressions.push(Regression::MetricFlipped { name: name.clone(), previous_reason, reason: reason.clone() });
+        }
+    }
+
+    for (category, &previous_score) in &previous.category_scores {
+        if let Some(&current_score) = current.category_scores.get(category)
+            && previous_score - current_score > category_score_threshold
+        {
+            regressions.push(Regression::CategoryScoreDropped { category: *category, previous: previous_score, current: current_score });
+        }
+    }
+
+    if let Some(advisory_data) = facts.advisory_data.clone().ok() {
+        let previous_ids: HashSet<&str> = previous.advisory_ids.iter().map(String::as_str).collect();
+        for record in &advisory_data.records {
+            if !previous_ids.contains(record.id.as_str()) {
+                regressions.push(Regression::NewAdvisory { rustsec_id: record.id.clone() });
+            }
+        }
+    }
+
+    regressions
+}
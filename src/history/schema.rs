@@ -0,0 +1,53 @@
+//! SQLite schema and migrations for [`super::HistoryStore`].
+
+use rusqlite::Connection;
+
+/// Schema version this build knows how to read and write. Bump alongside a new migration arm
+/// in [`migrate`].
+pub const CURRENT_SCHEMA_VERSION: i64 = 1;
+
+/// Create the history tables if they don't exist yet, and bring an older database forward to
+/// [`CURRENT_SCHEMA_VERSION`].
+///
+/// # Errors
+///
+/// Returns an error if any schema statement fails, or if the database declares a schema
+/// version newer than this build understands.
+pub fn migrate(conn: &Connection) -> anyhow::Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS schema_version (version INTEGER NOT NULL);
+
+         CREATE TABLE IF NOT EXISTS ranking_runs (
+             id              INTEGER PRIMARY KEY AUTOINCREMENT,
+             crate_name      TEXT NOT NULL,
+             crate_version   TEXT NOT NULL,
+             dependency_type TEXT NOT NULL,
+             recorded_at     TEXT NOT NULL,
+             overall_score   REAL NOT NULL,
+             category_scores TEXT NOT NULL,
+             details         TEXT NOT NULL,
+             advisory_ids    TEXT NOT NULL
+         );
+
+         CREATE INDEX IF NOT EXISTS idx_ranking_runs_crate
+             ON ranking_runs(crate_name, crate_version, recorded_at);",
+    )?;
+
+    let version: i64 = conn.query_row("SELECT version FROM schema_version LIMIT 1", [], |row| row.get(0)).unwrap_or(0);
+
+    anyhow::ensure!(
+        version <= CURRENT_SCHEMA_VERSION,
+        "history database declares schema version {version}, but this build only understands up to version {CURRENT_SCHEMA_VERSION}; upgrade cargo-rank to read it",
+    );
+
+    // No migration arms yet: every version up to and including `CURRENT_SCHEMA_VERSION` shares
+    // today's table shape. When that changes, match on `version` here and rewrite rows before
+    // bumping `schema_version`.
+    if version == 0 {
+        conn.execute("INSERT INTO schema_version (version) VALUES (?1)", [CURRENT_SCHEMA_VERSION])?;
+    } else if version < CURRENT_SCHEMA_VERSION {
+        conn.execute("UPDATE schema_version SET version = ?1", [CURRENT_SCHEMA_VERSION])?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,173 @@
+//! Persistent, queryable record of past [`RankingOutcome`]s, keyed by crate name + version.
+
+use crate::facts::CrateFacts;
+use crate::history::regression::{self, Regression};
+use crate::history::schema;
+use crate::metrics::MetricCategory;
+use crate::misc::DependencyType;
+use crate::ranking::{PolicyOutcome, RankingOutcome};
+use chrono::{DateTime, Utc};
+use rusqlite::{Connection, params};
+use std::collections::HashMap;
+use std::path::Path;
+use std::str::FromStr;
+
+/// A single past run, as returned by [`HistoryStore::latest`]/[`HistoryStore::history`].
+#[derive(Debug, Clone)]
+pub struct StoredOutcome {
+    pub crate_name: String,
+    pub crate_version: String,
+    pub dependency_type: DependencyType,
+    pub recorded_at: DateTime<Utc>,
+    pub overall_score: f64,
+    pub category_scores: HashMap<MetricCategory, f64>,
+
+    /// Every [`crate::metrics::Metric`] and `custom_metrics` script outcome from that run,
+    /// keyed by name (a `Metric`'s `Display` string, or a [`crate::config::ScriptPolicy`]
+    /// name).
+    pub details: HashMap<String, PolicyOutcome>,
+
+    /// `AdvisoryRecord::id` for every advisory recorded against the crate in that run, used by
+    /// [`super::diff`] to spot newly-matched advisories.
+    pub advisory_ids: Vec<String>,
+}
+
+/// SQLite-backed store of past [`RankingOutcome`]s, enabling "what changed since last run"
+/// regression detection across `cargo aprz` invocations.
+#[derive(Debug)]
+pub struct HistoryStore {
+    conn: Connection,
+}
+
+impl HistoryStore {
+    /// Open (creating if absent) the history database at `path`, applying any pending schema
+    /// migration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database can't be opened or migrated.
+    pub fn open(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let conn = Connection::open(path)?;
+        schema::migrate(&conn)?;
+        Ok(Self { conn })
+    }
+
+    /// Open an in-memory history database, mainly useful for one-off regression checks that
+    /// shouldn't accumulate on disk.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database can't be migrated.
+    pub fn open_in_memory() -> anyhow::Result<Self> {
+        let conn = Connection::open_in_memory()?;
+        schema::migrate(&conn)?;
+        Ok(Self { conn })
+    }
+
+    /// Record `outcome` for `crate_name`/`crate_version`, combining `Metric` and
+    /// `custom_metrics` details under one JSON blob, and stamping the current advisory ids
+    /// from `facts.advisory_data`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the insert fails.
+    pub fn record(&self, crate_name: &str, crate_version: &str, facts: &CrateFacts, outcome: &RankingOutcome) -> anyhow::Result<()> {
+        let mut details: HashMap<String, PolicyOutcome> = outcome.details.iter().map(|(metric, o)| (metric.to_string(), o.clone())).collect();
+        details.extend(outcome.custom_details.iter().map(|(name, o)| (name.clone(), o.clone())));
+
+        let advisory_ids: Vec<String> =
+            facts.advisory_data.clone().ok().map(|data| data.records.iter().map(|record| record.id.clone()).collect()).unwrap_or_default();
+
+        let category_scores_json = serde_json::to_string(&outcome.category_scores).unwrap_or_default();
+        let details_json = serde_json::to_string(&details).unwrap_or_default();
+        let advisory_ids_json = serde_json::to_string(&advisory_ids).unwrap_or_default();
+
+        self.conn.execute(
+            "INSERT INTO ranking_runs
+                 (crate_name, crate_version, dependency_type, recorded_at, overall_score, category_scores, details, advisory_ids)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                crate_name,
+                crate_version,
+                outcome.dependency_type.to_string(),
+                Utc::now().to_rfc3339(),
+                outcome.overall_score,
+                category_scores_json,
+                details_json,
+                advisory_ids_json,
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Diff `outcome` against the crate's previous run (if any) via [`regression::diff`], then
+    /// record `outcome` as the new latest run — the single call a ranking pipeline needs to
+    /// make to get both persistence and regression detection out of this module.
+    ///
+    /// No call site for this exists anywhere in this tree yet: nothing here actually invokes
+    /// [`crate::ranking::Ranker::rank`] (the CLI commands that would — `commands::crates`,
+    /// `commands::deps` — aren't part of this snapshot), so this is staged ahead of that
+    /// integration rather than exercised by one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either the lookup or the insert fails.
+    pub fn record_and_diff(
+        &self,
+        crate_name: &str,
+        crate_version: &str,
+        facts: &CrateFacts,
+        outcome: &RankingOutcome,
+        category_score_threshold: f64,
+    ) -> anyhow::Result<Vec<Regression>> {
+        let previous = self.latest(crate_name, crate_version)?;
+        self.record(crate_name, crate_version, facts, outcome)?;
+        Ok(previous.map_or_else(Vec::new, |previous| regression::diff(&previous, outcome, facts, category_score_threshold)))
+    }
+
+    /// The most recently recorded run for `crate_name`/`crate_version`, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn latest(&self, crate_name: &str, crate_version: &str) -> anyhow::Result<Option<StoredOutcome>> {
+        let mut runs = self.history(crate_name, crate_version, 1)?;
+        Ok(if runs.is_empty() { None } else { Some(runs.remove(0)) })
+    }
+
+    /// Up to `limit` most recent runs for `crate_name`/`crate_version`, newest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn history(&self, crate_name: &str, crate_version: &str, limit: u32) -> anyhow::Result<Vec<StoredOutcome>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT crate_name, crate_version, dependency_type, recorded_at, overall_score, category_scores, details, advisory_ids
+             FROM ranking_runs
+             WHERE crate_name = ?1 AND crate_version = ?2
+             ORDER BY recorded_at DESC
+             LIMIT ?3",
+        )?;
+
+        let runs = stmt.query_map(params![crate_name, crate_version, limit], |row| {
+            let dependency_type: String = row.get(2)?;
+            let recorded_at: String = row.get(3)?;
+            let category_scores_json: String = row.get(5)?;
+            let details_json: String = row.get(6)?;
+            let advisory_ids_json: String = row.get(7)?;
+
+            Ok(StoredOutcome {
+                crate_name: row.get(0)?,
+                crate_version: row.get(1)?,
+                dependency_type: DependencyType::from_str(&dependency_type).unwrap_or(DependencyType::Standard),
+                recorded_at: DateTime::parse_from_rfc3339(&recorded_at).map_or_else(|_| Utc::now(), |dt| dt.with_timezone(&Utc)),
+                overall_score: row.get(4)?,
+                category_scores: serde_json::from_str(&category_scores_json).unwrap_or_default(),
+                details: serde_json::from_str(&details_json).unwrap_or_default(),
+                advisory_ids: serde_json::from_str(&advisory_ids_json).unwrap_or_default(),
+            })
+        })?;
+
+        Ok(runs.collect::<rusqlite::Result<Vec<_>>>()?)
+    }
+}
@@ -9,6 +9,10 @@ pub mod config;
 #[doc(hidden)]
 pub mod facts;
 
+#[cfg(feature = "sqlite-history")]
+#[doc(hidden)]
+pub mod history;
+
 #[doc(hidden)]
 pub mod metrics;
 
@@ -20,3 +24,6 @@ pub mod ranking;
 
 #[doc(hidden)]
 pub mod reports;
+
+#[doc(hidden)]
+pub mod telemetry;
@@ -9,24 +9,39 @@ pub enum Metric {
     License,
     Age,
     MinVersion,
+    Msrv,
     ReleaseCount,
+    AuditCoverage,
+    DependencyFreshness,
+    MaintenanceStatus,
 
     OverallDownloadCount,
     OneMonthDownloadCount,
+    AdjustedMonthlyDownloads,
+    DownloadTrend,
 
     OverallOwnerCount,
     UserOwnerCount,
     TeamOwnerCount,
 
     DependentCount,
+    RequiredReverseDependencyCount,
     DirectDependencyCount,
     TransitiveDependencyCount,
+    TarballSize,
+    UncompressedSize,
+    DependencyWeight,
+    InstalledWithDepsSize,
+    MinimalDependencyFootprint,
+    LinesOfCode,
 
     DocCoveragePercentage,
     BrokenDocLinkCount,
     CodeCoveragePercentage,
     FullySafeCode,
+    NonRustLanguageLineCount,
     ExampleCount,
+    CommentRatio,
 
     RepoStarCount,
     RepoForkCount,
@@ -47,6 +62,7 @@ pub enum Metric {
     MediumVulnerabilityCount,
     HighVulnerabilityCount,
     CriticalVulnerabilityCount,
+    CvssWeightedVulnerabilityScore,
     WarningCount,
     NoticeWarningCount,
     UnmaintainedWarningCount,
@@ -58,20 +74,33 @@ pub enum Metric {
     HistoricalMediumVulnerabilityCount,
     HistoricalHighVulnerabilityCount,
     HistoricalCriticalVulnerabilityCount,
+    HistoricalCvssWeightedVulnerabilityScore,
     HistoricalWarningCount,
     HistoricalNoticeWarningCount,
     HistoricalUnmaintainedWarningCount,
     HistoricalUnsoundWarningCount,
     HistoricalYankedWarningCount,
+    AdvisoryPatchResponsiveness,
+
+    TrustedReviewCount,
+    NegativeReviewCount,
+    ReviewThoroughnessScore,
 }
 
 impl Metric {
     #[must_use]
     pub const fn category(self) -> MetricCategory {
         match self {
-            Self::Age | Self::MinVersion | Self::ReleaseCount => MetricCategory::Stability,
+            Self::Age | Self::MinVersion | Self::Msrv | Self::ReleaseCount | Self::DependencyFreshness | Self::MaintenanceStatus => {
+                MetricCategory::Stability
+            }
 
-            Self::OverallDownloadCount | Self::OneMonthDownloadCount | Self::DependentCount => MetricCategory::Usage,
+            Self::OverallDownloadCount
+            | Self::OneMonthDownloadCount
+            | Self::AdjustedMonthlyDownloads
+            | Self::DownloadTrend
+            | Self::DependentCount
+            | Self::RequiredReverseDependencyCount => MetricCategory::Usage,
 
             Self::RepoStarCount | Self::RepoForkCount | Self::RepoSubscriberCount | Self::RepoContributorCount => MetricCategory::Community,
 
@@ -83,19 +112,30 @@ impl Metric {
             | Self::ClosedPullRequestCount
             | Self::PullRequestResponsiveness => MetricCategory::Activity,
 
-            Self::DocCoveragePercentage | Self::BrokenDocLinkCount | Self::ExampleCount => MetricCategory::Documentation,
+            Self::DocCoveragePercentage | Self::BrokenDocLinkCount | Self::ExampleCount | Self::CommentRatio => MetricCategory::Documentation,
 
             Self::OverallOwnerCount | Self::UserOwnerCount | Self::TeamOwnerCount | Self::License => MetricCategory::Ownership,
 
-            Self::CodeCoveragePercentage | Self::FullySafeCode => MetricCategory::Trustworthiness,
+            Self::CodeCoveragePercentage | Self::FullySafeCode | Self::AuditCoverage | Self::NonRustLanguageLineCount => {
+                MetricCategory::Trustworthiness
+            }
+
+            Self::TransitiveDependencyCount
+            | Self::DirectDependencyCount
+            | Self::TarballSize
+            | Self::UncompressedSize
+            | Self::DependencyWeight
+            | Self::InstalledWithDepsSize
+            | Self::MinimalDependencyFootprint
+            | Self::LinesOfCode => MetricCategory::Cost,
 
-            Self::TransitiveDependencyCount | Self::DirectDependencyCount => MetricCategory::Cost,
 
             Self::VulnerabilityCount
             | Self::LowVulnerabilityCount
             | Self::MediumVulnerabilityCount
             | Self::HighVulnerabilityCount
             | Self::CriticalVulnerabilityCount
+            | Self::CvssWeightedVulnerabilityScore
             | Self::WarningCount
             | Self::NoticeWarningCount
             | Self::UnmaintainedWarningCount
@@ -106,11 +146,15 @@ impl Metric {
             | Self::HistoricalMediumVulnerabilityCount
             | Self::HistoricalHighVulnerabilityCount
             | Self::HistoricalCriticalVulnerabilityCount
+            | Self::HistoricalCvssWeightedVulnerabilityScore
             | Self::HistoricalWarningCount
             | Self::HistoricalNoticeWarningCount
             | Self::HistoricalUnmaintainedWarningCount
             | Self::HistoricalUnsoundWarningCount
-            | Self::HistoricalYankedWarningCount => MetricCategory::Advisories,
+            | Self::HistoricalYankedWarningCount
+            | Self::AdvisoryPatchResponsiveness => MetricCategory::Advisories,
+
+            Self::TrustedReviewCount | Self::NegativeReviewCount | Self::ReviewThoroughnessScore => MetricCategory::Reviews,
         }
     }
 }
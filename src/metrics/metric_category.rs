@@ -13,4 +13,10 @@ pub enum MetricCategory {
     Trustworthiness,
     Cost,
     Advisories,
+    Reviews,
+
+    /// Synthetic bucket for every `custom_metrics` Rhai script (see
+    /// `crate::ranking::script_engine`), since their rules are arbitrary org-specific logic
+    /// rather than a fixed [`crate::metrics::Metric`].
+    Custom,
 }
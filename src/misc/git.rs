@@ -0,0 +1,110 @@
+//! Shared helpers for git-backed providers (advisory database sync, hosted commit stats):
+//! running `git` with a timeout and telling "repository doesn't exist" apart from a transient
+//! failure, plus a partial-clone sync strategy both providers can reuse.
+
+use anyhow::Context;
+use std::path::Path;
+use std::process::{Command, Output, Stdio};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+/// Default timeout for a single `git` invocation before it's killed and treated as a failure.
+pub const DEFAULT_GIT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Run `git <args>` in `dir` (the current directory if `None`), killing the process and
+/// returning an error if it doesn't finish within `timeout`.
+///
+/// # Errors
+///
+/// Returns an error if the process can't be spawned, times out, or exits with a non-zero
+/// status.
+pub fn run_git_with_timeout(dir: Option<&Path>, args: &[&str], timeout: Duration) -> anyhow::Result<Output> {
+    let mut command = Command::new("git");
+    command.args(args).stdout(Stdio::piped()).stderr(Stdio::piped());
+    if let Some(dir) = dir {
+        command.current_dir(dir);
+    }
+
+    let child = command.spawn().with_context(|| format!("failed to spawn `git {}`", args.join(" ")))?;
+    let pid = child.id();
+
+    let (tx, rx) = mpsc::channel();
+    let handle = thread::spawn(move || {
+        let _ = tx.send(child.wait_with_output());
+    });
+
+    match rx.recv_timeout(timeout) {
+        Ok(result) => {
+            let output = result.with_context(|| format!("`git {}` failed to run", args.join(" ")))?;
+            anyhow::ensure!(
+                output.status.success(),
+                "`git {}` exited with {}: {}",
+                args.join(" "),
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim(),
+            );
+            Ok(output)
+        }
+        Err(mpsc::RecvTimeoutError::Timeout) => {
+            kill_process(pid);
+            let _ = handle.join();
+            anyhow::bail!("`git {}` timed out after {timeout:?}", args.join(" "));
+        }
+        Err(mpsc::RecvTimeoutError::Disconnected) => {
+            anyhow::bail!("`git {}` worker thread panicked", args.join(" "));
+        }
+    }
+}
+
+#[cfg(unix)]
+fn kill_process(pid: u32) {
+    // Safety: `pid` came from the `Child` we just spawned and haven't reaped yet, and sending
+    // `SIGKILL` to it has no memory-safety implications.
+    unsafe {
+        libc::kill(libc::pid_t::try_from(pid).unwrap_or(i32::MAX), libc::SIGKILL);
+    }
+}
+
+#[cfg(not(unix))]
+fn kill_process(_pid: u32) {}
+
+/// Whether `output`'s stderr looks like git reporting that the remote repository doesn't exist,
+/// as opposed to a network/auth/timeout failure worth retrying.
+#[must_use]
+pub fn is_repo_not_found(output: &Output) -> bool {
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    stderr.contains("not found") || stderr.contains("does not exist") || stderr.contains("Repository not found")
+}
+
+/// Ensure `dir` holds an up-to-date partial clone of `remote_url`'s default branch: a fresh
+/// `--filter=blob:none` clone if `dir` has no `.git` yet, otherwise `fetch origin` followed by
+/// `reset --hard origin/HEAD`, so subsequent syncs only pull new commit/tree objects instead of
+/// re-downloading blobs already on disk.
+///
+/// # Errors
+///
+/// Returns an error if any underlying `git` invocation fails or times out.
+pub fn sync_partial_clone(dir: &Path, remote_url: &str, timeout: Duration) -> anyhow::Result<()> {
+    if dir.join(".git").is_dir() {
+        run_git_with_timeout(Some(dir), &["fetch", "--filter=blob:none", "origin"], timeout)?;
+        run_git_with_timeout(Some(dir), &["reset", "--hard", "origin/HEAD"], timeout)?;
+    } else {
+        if let Some(parent) = dir.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let dir_str = dir.to_string_lossy();
+        run_git_with_timeout(None, &["clone", "--filter=blob:none", remote_url, dir_str.as_ref()], timeout)?;
+    }
+    Ok(())
+}
+
+/// The current `HEAD` commit hash of the repository checked out at `dir`.
+///
+/// # Errors
+///
+/// Returns an error if `git rev-parse` fails, times out, or its output isn't valid UTF-8.
+pub fn head_commit(dir: &Path, timeout: Duration) -> anyhow::Result<String> {
+    let output = run_git_with_timeout(Some(dir), &["rev-parse", "HEAD"], timeout)?;
+    Ok(String::from_utf8(output.stdout)?.trim().to_string())
+}
@@ -0,0 +1,18 @@
+//! Small, broadly-shared types that don't belong to any single facts/config/ranking module.
+
+mod color_mode;
+mod dependency_type;
+mod dependency_types;
+pub mod git;
+mod output_format;
+mod profile;
+mod request_logging;
+mod version_selection;
+
+pub use color_mode::ColorMode;
+pub use dependency_type::DependencyType;
+pub use dependency_types::DependencyTypes;
+pub use output_format::OutputFormat;
+pub use profile::Profile;
+pub use request_logging::RequestLogging;
+pub use version_selection::VersionSelection;
@@ -0,0 +1,13 @@
+//! Output format selection for report generation.
+
+use clap::ValueEnum;
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum OutputFormat {
+    Console,
+    Xlsx,
+    Html,
+    Prometheus,
+    Github,
+    Sarif,
+}
@@ -0,0 +1,18 @@
+//! Dev/prod mode selection, borrowed from Skytable's "prod mode asserts sane defaults" idea.
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+/// Which guardrails [`crate::config::Config::validate`] enforces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[value(rename_all = "lowercase")]
+pub enum Profile {
+    /// No extra guardrails beyond the configuration's own internal consistency checks.
+    #[default]
+    Dev,
+    /// Additionally enforce the production floors/ceilings checked by
+    /// [`crate::config::Config::validate`], aborting if the configuration is too lenient to
+    /// run unattended in CI.
+    Prod,
+}
@@ -0,0 +1,21 @@
+//! Verbosity knob for `RequestTracker`'s per-request `tracing` spans.
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+/// How much detail [`crate::facts::request_tracker::RequestTracker`] emits through `tracing`
+/// for individual GitHub/docs.rs/codecov.io requests, separate from the aggregate progress bar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[value(rename_all = "lowercase")]
+pub enum RequestLogging {
+    /// No per-request tracing events; only the aggregate progress bar is updated.
+    #[default]
+    Off,
+    /// Log a `tracing` event when a request completes or fails, with its elapsed duration.
+    /// Skips the noisier issuance event, so interactive runs aren't cluttered.
+    CompletedOnly,
+    /// Log a `tracing` event for every issuance, completion, and failure. Intended for CI
+    /// runs that want a full timing breakdown of the query phase.
+    All,
+}
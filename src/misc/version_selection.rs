@@ -0,0 +1,16 @@
+//! Version-selection mode for crates appearing at multiple versions in a resolved graph.
+
+use clap::ValueEnum;
+
+/// Which version(s) of a crate to appraise when the resolved dependency graph contains
+/// several, mirroring cargo's `VersionOrdering`/`minimal-versions` resolver knobs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "lowercase")]
+pub enum VersionSelection {
+    /// Keep only the highest version per crate name — the resolver's normal behavior.
+    Newest,
+    /// Keep only the lowest version per crate name — a `-Z minimal-versions` style baseline.
+    Minimal,
+    /// Appraise every instance of every crate, regardless of version.
+    All,
+}
@@ -1,12 +1,14 @@
 //! Rule evaluation logic for crates.
 
-use crate::config::{Config, Policy, ResponsivenessPolicy};
+use crate::config::{Config, MsrvPolicy, Policy, ResponsivenessPolicy, ReverseDepsPolicy};
 use crate::facts::AgeStats;
 use crate::facts::OwnerKind;
+use crate::facts::freshness::DependencyFreshnessData;
+use crate::facts::vet::AuditPath;
 use crate::facts::{CrateFacts, ProviderResult};
 use crate::metrics::Metric;
 use crate::misc::DependencyType;
-use crate::ranking::PolicyOutcome;
+use crate::ranking::{PolicyOutcome, ScoreOutcome};
 use chrono::{Duration, Utc};
 use std::collections::HashMap;
 
@@ -15,35 +17,61 @@ struct MetricCalculator<'a> {
     facts: &'a CrateFacts,
     dependency_type: DependencyType,
     results: &'a mut HashMap<Metric, PolicyOutcome>,
+    scores: &'a mut HashMap<Metric, ScoreOutcome>,
 }
 
 /// Calculate all the metrics for a given crate
-pub fn calculate(config: &Config, facts: &CrateFacts, dependency_type: DependencyType, results: &mut HashMap<Metric, PolicyOutcome>) {
+pub fn calculate(
+    config: &Config,
+    facts: &CrateFacts,
+    dependency_type: DependencyType,
+    results: &mut HashMap<Metric, PolicyOutcome>,
+    scores: &mut HashMap<Metric, ScoreOutcome>,
+) {
     let mut calc = MetricCalculator {
         config,
         facts,
         dependency_type,
         results,
+        scores,
     };
 
     calc.license();
     calc.age();
     calc.min_version();
+    calc.msrv();
     calc.release_count();
+    calc.audit_coverage();
+    calc.trusted_review_count();
+    calc.negative_review_count();
+    calc.review_thoroughness_score();
+    calc.dependency_freshness();
+    calc.maintenance_status();
     calc.overall_download_count();
     calc.one_month_download_count();
+    calc.adjusted_monthly_downloads();
+    calc.download_trend();
     calc.overall_owner_count();
     calc.team_owner_count();
     calc.user_owner_count();
     calc.direct_dependency_count();
     calc.dependent_count();
+    calc.required_reverse_dependency_count();
+    calc.tarball_size();
+    calc.uncompressed_size();
+    calc.dependency_weight();
+    calc.installed_with_deps_size();
+    calc.minimal_dependency_footprint();
+    calc.lines_of_code();
 
     calc.doc_coverage_percentage();
     calc.broken_doc_link_count();
     calc.code_coverage_percentage();
     calc.fully_safe_code();
+    calc.non_rust_language_line_count();
     calc.transitive_dependency_count();
     calc.example_count();
+    calc.comment_ratio();
 
     calc.repo_contributor_count();
     calc.repo_star_count();
@@ -62,6 +90,7 @@ pub fn calculate(config: &Config, facts: &CrateFacts, dependency_type: Dependenc
     calc.medium_vulnerability_count();
     calc.high_vulnerability_count();
     calc.critical_vulnerability_count();
+    calc.cvss_weighted_vulnerability_score();
     calc.warning_count();
     calc.notice_warning_count();
     calc.unmaintained_warning_count();
@@ -73,11 +102,13 @@ pub fn calculate(config: &Config, facts: &CrateFacts, dependency_type: Dependenc
     calc.historical_medium_vulnerability_count();
     calc.historical_high_vulnerability_count();
     calc.historical_critical_vulnerability_count();
+    calc.historical_cvss_weighted_vulnerability_score();
     calc.historical_warning_count();
     calc.historical_notice_warning_count();
     calc.historical_unmaintained_warning_count();
     calc.historical_unsound_warning_count();
     calc.historical_yanked_warning_count();
+    calc.advisory_patch_responsiveness();
 }
 
 impl MetricCalculator<'_> {
@@ -89,6 +120,13 @@ impl MetricCalculator<'_> {
         let license = &crate_version_data.license;
         let license_str = license.as_deref().unwrap_or("None");
 
+        let is_approved = self
+            .config
+            .license
+            .iter()
+            .filter(|p| p.dependency_types().contains(self.dependency_type))
+            .any(|p| license.as_ref().is_some_and(|l| p.check_license(l)));
+
         self.apply_generic_policy(
             Metric::License,
             &self.config.license,
@@ -96,6 +134,7 @@ impl MetricCalculator<'_> {
             |_| format!("'{license_str}'"),
             || format!("'{license_str}'; not a supported license type"),
         );
+        self.add_score(Metric::License, if is_approved { 1.0 } else { 0.0 });
     }
 
     /// Evaluate the age of the crate (time since first version was released).
@@ -123,6 +162,8 @@ impl MetricCalculator<'_> {
             |_| format!("{age_days} days"),
             || format!("{age_days} days (need >= {min_days})"),
         );
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::Age, Self::ramp_up(age_days as f64, *min_days as f64));
     }
 
     /// Evaluate if the crate has reached a stable version (1.0+).
@@ -148,6 +189,69 @@ impl MetricCalculator<'_> {
             |_| format!("v{major_version}"),
             || format!("v{major_version} (need >= v{min_version})"),
         );
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::MinVersion, Self::ramp_up(major_version as f64, *min_version as f64));
+    }
+
+    /// Evaluate the crate's declared MSRV (and, optionally, edition) against the configured
+    /// ceiling. A crate that declares no `rust-version` gets a distinct "unspecified MSRV"
+    /// outcome rather than silently passing. When every applicable policy is configured with
+    /// `prefer_compatible`, a crate whose MSRV exceeds the ceiling is reported as a warning
+    /// rather than a hard failure.
+    fn msrv(&mut self) {
+        let ProviderResult::Found(crate_version_data) = &self.facts.crate_version_data else {
+            unreachable!("analyzable crate must have Found data");
+        };
+
+        let policies: Vec<_> = self
+            .config
+            .msrv
+            .iter()
+            .filter(|p| p.dependency_types().contains(self.dependency_type))
+            .collect();
+
+        if policies.is_empty() {
+            self.add_not_matched(Metric::Msrv, "no policy defined".to_string());
+            return;
+        }
+
+        let Some(raw_rust_version) = &crate_version_data.rust_version else {
+            self.add_not_matched(Metric::Msrv, "unspecified MSRV".to_string());
+            return;
+        };
+
+        let Some(rust_version) = MsrvPolicy::parse_rust_version(raw_rust_version) else {
+            self.add_not_matched(Metric::Msrv, format!("unparseable rust-version '{raw_rust_version}'"));
+            return;
+        };
+
+        for policy in &policies {
+            if policy.matches(&rust_version, crate_version_data.edition) {
+                let points = self.scale_points(Metric::Msrv, policy.points());
+                self.add_matched(Metric::Msrv, points, format!("requires Rust {rust_version}"));
+                self.add_score(Metric::Msrv, 1.0);
+                return;
+            }
+        }
+
+        let message = format!("requires Rust {rust_version} (need <= {})", Self::least_strict_ceiling(&policies));
+        if policies.iter().all(|p| p.prefer_compatible) {
+            let warning_points = policies.iter().map(|p| p.warning_points).fold(0.0, f64::max);
+            let points = self.scale_points(Metric::Msrv, warning_points);
+            self.add_warning(Metric::Msrv, points, message);
+        } else {
+            self.add_not_matched(Metric::Msrv, message);
+        }
+        self.add_score(Metric::Msrv, 0.0);
+    }
+
+    /// Returns the most lenient `max_rust_version` among `policies`, for failure messages.
+    fn least_strict_ceiling(policies: &[&MsrvPolicy]) -> String {
+        policies
+            .iter()
+            .filter_map(|p| MsrvPolicy::parse_rust_version(&p.max_rust_version).map(|v| (v, &p.max_rust_version)))
+            .max_by_key(|(v, _)| v.clone())
+            .map_or_else(|| "?".to_string(), |(_, raw)| raw.clone())
     }
 
     /// Evaluate how frequently the crate is released.
@@ -167,6 +271,213 @@ impl MetricCalculator<'_> {
         );
     }
 
+    /// Evaluate whether the crate version is covered by a cargo-vet-style audit graph for
+    /// every configured required criterion. A violation entry fails the metric outright; an
+    /// unreachable target names the nearest audited version so the reason explains what a
+    /// delta audit would need to cover.
+    fn audit_coverage(&mut self) {
+        let ProviderResult::Found(vet_data) = &self.facts.vet_data else {
+            return;
+        };
+
+        let policies: Vec<_> = self
+            .config
+            .audit_coverage
+            .iter()
+            .filter(|p| p.dependency_types().contains(self.dependency_type))
+            .collect();
+
+        if policies.is_empty() {
+            self.add_not_matched(Metric::AuditCoverage, "no policy defined".to_string());
+            return;
+        }
+
+        for policy in &policies {
+            match vet_data.coverage(&policy.required_criteria) {
+                AuditPath::Certified => {
+                    let points = self.scale_points(Metric::AuditCoverage, policy.points());
+                    let criteria = policy.required_criteria.join(", ");
+                    self.add_matched(Metric::AuditCoverage, points, format!("audited for {criteria}"));
+                    self.add_score(Metric::AuditCoverage, 1.0);
+                    return;
+                }
+                AuditPath::Violated => {
+                    self.add_not_matched(Metric::AuditCoverage, format!("{} has a recorded violation", vet_data.version));
+                    self.add_score(Metric::AuditCoverage, 0.0);
+                    return;
+                }
+                AuditPath::Uncertified { .. } => {}
+            }
+        }
+
+        let nearest = policies
+            .iter()
+            .find_map(|p| match vet_data.coverage(&p.required_criteria) {
+                AuditPath::Uncertified { nearest_audited } => nearest_audited,
+                _ => None,
+            });
+
+        let message = nearest.map_or_else(
+            || format!("{} is unaudited; no delta path from a trusted root exists", vet_data.version),
+            |nearest| format!("{nearest} audited; {} under review needs a delta audit", vet_data.version),
+        );
+        self.add_not_matched(Metric::AuditCoverage, message);
+        self.add_score(Metric::AuditCoverage, 0.0);
+    }
+
+    /// Evaluate the number of cargo-crev reviews authored by a trusted identity (more is
+    /// better).
+    fn trusted_review_count(&mut self) {
+        let ProviderResult::Found(review_data) = &self.facts.review_data else {
+            return;
+        };
+        let count = u64::from(review_data.trusted_review_count);
+
+        let min_count = &self
+            .config
+            .trusted_review_count
+            .iter()
+            .filter(|p| p.dependency_types().contains(self.dependency_type))
+            .map(|p| u64::from(p.min_count))
+            .min()
+            .unwrap_or(0);
+
+        self.apply_generic_policy(
+            Metric::TrustedReviewCount,
+            &self.config.trusted_review_count,
+            |p| count >= u64::from(p.min_count),
+            |_| format!("{count} reviews from trusted identities"),
+            || format!("{count} reviews from trusted identities (need >= {min_count})"),
+        );
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::TrustedReviewCount, Self::ramp_up(count as f64, *min_count as f64));
+    }
+
+    /// Evaluate the number of negative cargo-crev reviews authored by a trusted identity
+    /// (fewer is better).
+    fn negative_review_count(&mut self) {
+        let ProviderResult::Found(review_data) = &self.facts.review_data else {
+            return;
+        };
+        let count = u64::from(review_data.negative_review_count);
+        let max_count = self.get_max_count(&self.config.negative_review_count);
+
+        self.apply_generic_policy(
+            Metric::NegativeReviewCount,
+            &self.config.negative_review_count,
+            |p| count <= u64::from(p.max_count),
+            |_| format!("{count} negative reviews from trusted identities"),
+            || format!("{count} negative reviews from trusted identities (need <= {max_count})"),
+        );
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::NegativeReviewCount, Self::ramp_down(count as f64, max_count as f64));
+    }
+
+    /// Evaluate average review thoroughness across trusted cargo-crev reviews (more is
+    /// better).
+    fn review_thoroughness_score(&mut self) {
+        let ProviderResult::Found(review_data) = &self.facts.review_data else {
+            return;
+        };
+        if review_data.trusted_review_count == 0 {
+            self.add_not_matched(Metric::ReviewThoroughnessScore, "no reviews from trusted identities".to_string());
+            return;
+        }
+        let score = review_data.average_thoroughness;
+        // `get_min_score` returns the real minimum across applicable policies, so a configured
+        // threshold actually constrains this score instead of always collapsing to `0.0`.
+        let min_score = self.get_min_score(&self.config.review_thoroughness_score);
+
+        self.apply_generic_policy(
+            Metric::ReviewThoroughnessScore,
+            &self.config.review_thoroughness_score,
+            |p| score >= p.min_score,
+            |_| format!("average review thoroughness {score:.1}"),
+            || format!("average review thoroughness {score:.1} (need >= {min_score:.1})"),
+        );
+        self.add_score(Metric::ReviewThoroughnessScore, Self::ramp_up(score, min_score));
+    }
+
+    /// Evaluate the `badges.maintenance.status` declared in this version's `Cargo.toml` against
+    /// the accepted statuses per dependency type. A crate with no maintenance badge at all is
+    /// a distinct "unspecified" outcome rather than a pass.
+    fn maintenance_status(&mut self) {
+        let ProviderResult::Found(crate_version_data) = &self.facts.crate_version_data else {
+            unreachable!("analyzable crate must have Found data");
+        };
+
+        let Some(status) = crate_version_data.maintenance_status else {
+            self.add_warning(Metric::MaintenanceStatus, 0.0, "maintenance: unspecified (no maintenance badge)".to_string());
+            self.add_score(Metric::MaintenanceStatus, 0.5);
+            return;
+        };
+
+        let policies: Vec<_> = self
+            .config
+            .maintenance_status
+            .iter()
+            .filter(|p| p.dependency_types().contains(self.dependency_type))
+            .collect();
+
+        if policies.is_empty() {
+            self.add_not_matched(Metric::MaintenanceStatus, "no policy defined".to_string());
+            return;
+        }
+
+        for policy in &policies {
+            if policy.accepted_statuses.contains(&status) {
+                let points = self.scale_points(Metric::MaintenanceStatus, policy.points());
+                self.add_matched(Metric::MaintenanceStatus, points, format!("maintenance: {status}"));
+                self.add_score(Metric::MaintenanceStatus, 1.0);
+                return;
+            }
+        }
+
+        self.add_not_matched(Metric::MaintenanceStatus, format!("maintenance: {status} (not an accepted status)"));
+        self.add_score(Metric::MaintenanceStatus, 0.0);
+    }
+
+    /// Evaluate how outdated the crate's direct dependencies are, relative to their newest
+    /// release on the registry. Fails when the mean freshness across dependencies drops
+    /// below the configured threshold, naming the staleest few dependencies.
+    fn dependency_freshness(&mut self) {
+        let ProviderResult::Found(freshness_data) = &self.facts.dependency_freshness_data else {
+            return;
+        };
+
+        #[expect(
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            reason = "mean_freshness() is clamped to [0, 1], so *100 always fits in u8"
+        )]
+        let mean_percentage = (freshness_data.mean_freshness() * 100.0).round() as u8;
+
+        let min_percentage = &self
+            .config
+            .dependency_freshness
+            .iter()
+            .filter(|p| p.dependency_types().contains(self.dependency_type))
+            .map(|p| p.min_percentage)
+            .min()
+            .unwrap_or(0);
+
+        self.apply_generic_policy(
+            Metric::DependencyFreshness,
+            &self.config.dependency_freshness,
+            |p| mean_percentage >= p.min_percentage,
+            |_| format!("{mean_percentage}% mean dependency freshness"),
+            || {
+                let stalest = DependencyFreshnessData::stalest(freshness_data, 3)
+                    .iter()
+                    .map(|d| format!("{} ({})", d.name, d.used_version))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("{mean_percentage}% mean dependency freshness (need >= {min_percentage}%); staleest: {stalest}")
+            },
+        );
+        self.add_score(Metric::DependencyFreshness, freshness_data.mean_freshness());
+    }
+
     /// Evaluate overall download count since publication.
     fn overall_download_count(&mut self) {
         let ProviderResult::Found(crate_overall_data) = &self.facts.crate_overall_data else {
@@ -190,6 +501,8 @@ impl MetricCalculator<'_> {
             |_| format!("{downloads} total downloads"),
             || format!("{downloads} total downloads (need >= {min_downloads})"),
         );
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::OverallDownloadCount, Self::ramp_up(downloads as f64, *min_downloads as f64));
     }
 
     /// Evaluate download count in the last month.
@@ -216,6 +529,84 @@ impl MetricCalculator<'_> {
             |_| format!("{recent_downloads} downloads in the last month"),
             || format!("{recent_downloads} downloads in the last month (need >= {min_downloads})"),
         );
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::OneMonthDownloadCount, Self::ramp_up(recent_downloads as f64, *min_downloads as f64));
+    }
+
+    /// Evaluate monthly downloads discounted by the single most-downloaded direct
+    /// dependent, so crates popular only because of one dominant consumer don't appear
+    /// artificially popular (more is better).
+    fn adjusted_monthly_downloads(&mut self) {
+        let ProviderResult::Found(crate_overall_data) = &self.facts.crate_overall_data else {
+            unreachable!("analyzable crate must have Found data");
+        };
+        let recent_downloads = crate_overall_data.monthly_downloads.last().map_or(0, |(_, downloads)| *downloads);
+        let adjusted_downloads = recent_downloads.saturating_sub(crate_overall_data.most_downloaded_dependent_monthly_downloads);
+
+        let min_downloads = &self
+            .config
+            .adjusted_monthly_downloads
+            .iter()
+            .filter(|p| p.dependency_types().contains(self.dependency_type))
+            .map(|p| u64::from(p.min_count))
+            .min()
+            .unwrap_or(0);
+
+        self.apply_generic_policy(
+            Metric::AdjustedMonthlyDownloads,
+            &self.config.adjusted_monthly_downloads,
+            |p| adjusted_downloads >= u64::from(p.min_count),
+            |_| format!("{adjusted_downloads} adjusted downloads in the last month ({recent_downloads} raw)"),
+            || {
+                format!(
+                    "{adjusted_downloads} adjusted downloads in the last month ({recent_downloads} raw, need >= {min_downloads})"
+                )
+            },
+        );
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::AdjustedMonthlyDownloads, Self::ramp_up(adjusted_downloads as f64, *min_downloads as f64));
+    }
+
+    /// Evaluate download momentum by comparing the trailing three months' average downloads
+    /// against the three months before that, so a crate trending down is distinguished from
+    /// one holding steady or growing, rather than judged solely on a single cumulative count.
+    fn download_trend(&mut self) {
+        let ProviderResult::Found(crate_overall_data) = &self.facts.crate_overall_data else {
+            unreachable!("analyzable crate must have Found data");
+        };
+        let monthly_downloads = &crate_overall_data.monthly_downloads;
+
+        const WINDOW: usize = 3;
+        if monthly_downloads.len() < WINDOW * 2 {
+            self.add_not_matched(Metric::DownloadTrend, "not enough download history to compute a trend".to_string());
+            return;
+        }
+
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        let window_avg = |downloads: &[(chrono::NaiveDate, u64)]| -> f64 {
+            downloads.iter().map(|(_, count)| *count as f64).sum::<f64>() / downloads.len() as f64
+        };
+
+        let len = monthly_downloads.len();
+        let recent_avg = window_avg(&monthly_downloads[len - WINDOW..]);
+        let prior_avg = window_avg(&monthly_downloads[len - WINDOW * 2..len - WINDOW]);
+
+        if prior_avg <= 0.0 {
+            self.add_not_matched(Metric::DownloadTrend, "no downloads in the prior period to compare against".to_string());
+            return;
+        }
+
+        let growth_percentage = (recent_avg - prior_avg) / prior_avg * 100.0;
+        let min_growth = self.get_min_score(&self.config.download_trend);
+
+        self.apply_generic_policy(
+            Metric::DownloadTrend,
+            &self.config.download_trend,
+            |p| growth_percentage >= p.min_score,
+            |_| format!("{growth_percentage:.1}% download growth over the trailing 3 months"),
+            || format!("{growth_percentage:.1}% download growth over the trailing 3 months (need >= {min_growth:.1}%)"),
+        );
+        self.add_score(Metric::DownloadTrend, Self::ramp_trend(growth_percentage, min_growth));
     }
 
     /// Evaluate the total number of owners (users + teams).
@@ -241,6 +632,8 @@ impl MetricCalculator<'_> {
             |_| format!("{owner_count} total owners"),
             || format!("{owner_count} total owners (need >= {min_owner_count})"),
         );
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::OverallOwnerCount, Self::ramp_up(owner_count as f64, *min_owner_count as f64));
     }
 
     /// Evaluate the number of team owners.
@@ -263,6 +656,8 @@ impl MetricCalculator<'_> {
             |_| format!("{owner_team_count} team owners"),
             || format!("{owner_team_count} team owners (need >= {min_owner_team_count})"),
         );
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::TeamOwnerCount, Self::ramp_up(owner_team_count as f64, *min_owner_team_count as f64));
     }
 
     /// Evaluate the number of user owners.
@@ -285,20 +680,16 @@ impl MetricCalculator<'_> {
             |_| format!("{owner_user_count} user owners"),
             || format!("{owner_user_count} user owners (need >= {min_owner_user_count})"),
         );
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::UserOwnerCount, Self::ramp_up(owner_user_count as f64, *min_owner_user_count as f64));
     }
 
     /// Evaluate the number of direct dependencies (fewer is better).
-    #[expect(
-        clippy::unused_self,
-        clippy::missing_const_for_fn,
-        reason = "Disabled placeholder until direct_dependencies available from CodebaseData"
-    )]
-    fn direct_dependency_count(&self) {
-        // Note: Direct dependency count is not currently available from crates.io data.
-        // It will need to be sourced from CodebaseData (via cargo metadata).
-
-        /* Disabled until direct_dependencies is available from source_data
-        let direct_deps = self.facts.crate_version_data.direct_dependencies;
+    fn direct_dependency_count(&mut self) {
+        let ProviderResult::Found(size_data) = &self.facts.size_data else {
+            return;
+        };
+        let direct_deps = u64::from(size_data.direct_dependency_count);
 
         let max_direct_deps = &self
             .config
@@ -316,7 +707,186 @@ impl MetricCalculator<'_> {
             |_| format!("{direct_deps} direct dependencies"),
             || format!("{direct_deps} direct dependencies (need < {max_direct_deps})"),
         );
-        */
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::DirectDependencyCount, Self::ramp_down(direct_deps as f64, *max_direct_deps as f64));
+    }
+
+    /// Evaluate the size of the published tarball (smaller is better).
+    fn tarball_size(&mut self) {
+        let ProviderResult::Found(size_data) = &self.facts.size_data else {
+            return;
+        };
+        let tarball_bytes = size_data.tarball_bytes;
+
+        let max_tarball_bytes = &self
+            .config
+            .tarball_size
+            .iter()
+            .filter(|p| p.dependency_types().contains(self.dependency_type))
+            .map(|p| u64::from(p.max_count))
+            .max()
+            .unwrap_or(0);
+
+        self.apply_generic_policy(
+            Metric::TarballSize,
+            &self.config.tarball_size,
+            |p| tarball_bytes <= u64::from(p.max_count),
+            |_| format!("{tarball_bytes} byte tarball"),
+            || format!("{tarball_bytes} byte tarball (need <= {max_tarball_bytes})"),
+        );
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::TarballSize, Self::ramp_down(tarball_bytes as f64, *max_tarball_bytes as f64));
+    }
+
+    /// Evaluate the estimated weight of this crate's dependency closure with default
+    /// features enabled (smaller is better).
+    fn dependency_weight(&mut self) {
+        let ProviderResult::Found(size_data) = &self.facts.size_data else {
+            return;
+        };
+        let weight_bytes = size_data.typical_dependency_bytes;
+
+        let max_weight_bytes = &self
+            .config
+            .dependency_weight
+            .iter()
+            .filter(|p| p.dependency_types().contains(self.dependency_type))
+            .map(|p| u64::from(p.max_count))
+            .max()
+            .unwrap_or(0);
+
+        self.apply_generic_policy(
+            Metric::DependencyWeight,
+            &self.config.dependency_weight,
+            |p| weight_bytes <= u64::from(p.max_count),
+            |_| format!("{weight_bytes} bytes of dependencies"),
+            || format!("{weight_bytes} bytes of dependencies (need <= {max_weight_bytes})"),
+        );
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::DependencyWeight, Self::ramp_down(weight_bytes as f64, *max_weight_bytes as f64));
+    }
+
+    /// Evaluate the uncompressed size of this crate's own source (smaller is better).
+    fn uncompressed_size(&mut self) {
+        let ProviderResult::Found(size_data) = &self.facts.size_data else {
+            return;
+        };
+        let uncompressed_bytes = size_data.uncompressed_bytes;
+
+        let max_uncompressed_bytes = &self
+            .config
+            .uncompressed_size
+            .iter()
+            .filter(|p| p.dependency_types().contains(self.dependency_type))
+            .map(|p| u64::from(p.max_count))
+            .max()
+            .unwrap_or(0);
+
+        self.apply_generic_policy(
+            Metric::UncompressedSize,
+            &self.config.uncompressed_size,
+            |p| uncompressed_bytes <= u64::from(p.max_count),
+            |_| format!("crate is {}", Self::format_mib(uncompressed_bytes)),
+            || format!("crate is {} (need <= {})", Self::format_mib(uncompressed_bytes), Self::format_mib(*max_uncompressed_bytes)),
+        );
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::UncompressedSize, Self::ramp_down(uncompressed_bytes as f64, *max_uncompressed_bytes as f64));
+    }
+
+    /// Evaluate the total installed footprint of this crate plus its dependency closure with
+    /// default features enabled (smaller is better). Complements [`Self::dependency_weight`] by
+    /// gating on the combined figure a team would actually see land on disk.
+    fn installed_with_deps_size(&mut self) {
+        let ProviderResult::Found(size_data) = &self.facts.size_data else {
+            return;
+        };
+        let own_bytes = size_data.uncompressed_bytes;
+        let with_deps_bytes = size_data.typical_dependency_bytes;
+        let total_bytes = with_deps_bytes;
+
+        let max_total_bytes = &self
+            .config
+            .installed_with_deps_size
+            .iter()
+            .filter(|p| p.dependency_types().contains(self.dependency_type))
+            .map(|p| u64::from(p.max_count))
+            .max()
+            .unwrap_or(0);
+
+        self.apply_generic_policy(
+            Metric::InstalledWithDepsSize,
+            &self.config.installed_with_deps_size,
+            |p| total_bytes <= u64::from(p.max_count),
+            |_| format!("crate is {}, {} with deps", Self::format_mib(own_bytes), Self::format_mib(with_deps_bytes)),
+            || {
+                format!(
+                    "crate is {}, {} with deps (need <= {})",
+                    Self::format_mib(own_bytes),
+                    Self::format_mib(with_deps_bytes),
+                    Self::format_mib(*max_total_bytes)
+                )
+            },
+        );
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::InstalledWithDepsSize, Self::ramp_down(total_bytes as f64, *max_total_bytes as f64));
+    }
+
+    /// Evaluate the installed footprint of this crate plus its dependency closure with
+    /// default features disabled (smaller is better). Complements
+    /// [`Self::installed_with_deps_size`]'s default-features figure with the floor a consumer
+    /// who opts out of every optional feature would still pay.
+    fn minimal_dependency_footprint(&mut self) {
+        let ProviderResult::Found(size_data) = &self.facts.size_data else {
+            return;
+        };
+        let minimal_bytes = size_data.minimal_dependency_bytes;
+
+        let max_minimal_bytes = &self
+            .config
+            .minimal_dependency_footprint
+            .iter()
+            .filter(|p| p.dependency_types().contains(self.dependency_type))
+            .map(|p| u64::from(p.max_count))
+            .max()
+            .unwrap_or(0);
+
+        self.apply_generic_policy(
+            Metric::MinimalDependencyFootprint,
+            &self.config.minimal_dependency_footprint,
+            |p| minimal_bytes <= u64::from(p.max_count),
+            |_| format!("{} with no default features", Self::format_mib(minimal_bytes)),
+            || format!("{} with no default features (need <= {})", Self::format_mib(minimal_bytes), Self::format_mib(*max_minimal_bytes)),
+        );
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::MinimalDependencyFootprint, Self::ramp_down(minimal_bytes as f64, *max_minimal_bytes as f64));
+    }
+
+    /// Evaluate the total lines of code across the crate's source tree, summed over every
+    /// language it ships (smaller is better).
+    fn lines_of_code(&mut self) {
+        let ProviderResult::Found(codebase_data) = &self.facts.codebase_data else {
+            return;
+        };
+        let code_lines = codebase_data.total_code_lines();
+
+        let max_code_lines = &self
+            .config
+            .lines_of_code
+            .iter()
+            .filter(|p| p.dependency_types().contains(self.dependency_type))
+            .map(|p| u64::from(p.max_count))
+            .max()
+            .unwrap_or(0);
+
+        self.apply_generic_policy(
+            Metric::LinesOfCode,
+            &self.config.lines_of_code,
+            |p| code_lines <= u64::from(p.max_count),
+            |_| format!("{code_lines} lines of code"),
+            || format!("{code_lines} lines of code (need <= {max_code_lines})"),
+        );
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::LinesOfCode, Self::ramp_down(code_lines as f64, *max_code_lines as f64));
     }
 
     /// Evaluate the number of dependents (more is better).
@@ -342,6 +912,54 @@ impl MetricCalculator<'_> {
             |_| format!("{deps} dependents"),
             || format!("{deps} dependents (need >= {min_deps})"),
         );
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::DependentCount, Self::ramp_up(deps as f64, *min_deps as f64));
+    }
+
+    /// Evaluate the number of reverse dependencies, optionally restricted to required
+    /// (non-optional) dependents on a per-policy basis via [`ReverseDepsPolicy::required_only`].
+    fn required_reverse_dependency_count(&mut self) {
+        let ProviderResult::Found(crate_overall_data) = &self.facts.crate_overall_data else {
+            unreachable!("analyzable crate must have Found data");
+        };
+
+        let deps_for = |p: &ReverseDepsPolicy| {
+            if p.required_only {
+                crate_overall_data.required_dependents
+            } else {
+                crate_overall_data.dependents
+            }
+        };
+
+        let min_deps = &self
+            .config
+            .required_reverse_dependency_count
+            .iter()
+            .filter(|p| p.dependency_types().contains(self.dependency_type))
+            .map(|p| u64::from(p.min_count))
+            .min()
+            .unwrap_or(0);
+
+        self.apply_generic_policy(
+            Metric::RequiredReverseDependencyCount,
+            &self.config.required_reverse_dependency_count,
+            |p| deps_for(p) >= u64::from(p.min_count),
+            |p| format!("{} reverse dependencies", deps_for(p)),
+            || format!("reverse dependencies (need >= {min_deps})"),
+        );
+        // Score against the same required-vs-total count the discrete check above used, rather
+        // than unconditionally the total, so a crate that fails a `required_only: true` policy
+        // can't still earn an inflated continuous score off its total dependent count. Mirrors
+        // `apply_generic_policy`'s first-match-wins predicate to find the policy that actually
+        // fired, falling back to the first applicable policy only when none matched (a `NoMatch`
+        // outcome, which isn't tied to any single policy).
+        let applicable_policies =
+            || self.config.required_reverse_dependency_count.iter().filter(|p| p.dependency_types().contains(self.dependency_type));
+        let scored_policy = applicable_policies().find(|p| deps_for(p) >= u64::from(p.min_count)).or_else(|| applicable_policies().next());
+        let scored_deps = scored_policy.map_or(crate_overall_data.dependents, deps_for);
+
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::RequiredReverseDependencyCount, Self::ramp_up(scored_deps as f64, *min_deps as f64));
     }
 
     /// Evaluate documentation coverage percentage.
@@ -373,6 +991,8 @@ impl MetricCalculator<'_> {
             |_| format!("{doc_coverage}% documentation coverage"),
             || format!("{doc_coverage}% documentation coverage (need >= {min_coverage}%)"),
         );
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::DocCoveragePercentage, Self::ramp_up(doc_coverage as f64, *min_coverage as f64));
     }
 
     /// Evaluate the number of broken documentation links.
@@ -404,6 +1024,8 @@ impl MetricCalculator<'_> {
             |_| format!("{broken_links} broken documentation links"),
             || format!("{broken_links} broken documentation links (need < {max_broken_links})"),
         );
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::BrokenDocLinkCount, Self::ramp_down(broken_links as f64, *max_broken_links as f64));
     }
 
     /// Evaluate codebase coverage percentage.
@@ -430,6 +1052,7 @@ impl MetricCalculator<'_> {
             |_| format!("{code_coverage:.1}% codebase coverage"),
             || format!("{code_coverage:.1}% codebase coverage (need >= {min_coverage:.1}%)"),
         );
+        self.add_score(Metric::CodeCoveragePercentage, Self::ramp_up(code_coverage, min_coverage));
     }
 
     /// Evaluate for unsafe codebase presence.
@@ -446,6 +1069,36 @@ impl MetricCalculator<'_> {
             |_| "crate contains no unsafe codebase".to_string(),
             || "crate contains unsafe codebase".to_string(),
         );
+        self.add_score(Metric::FullySafeCode, if has_unsafe { 0.0 } else { 1.0 });
+    }
+
+    /// Evaluate the amount of non-Rust source shipped alongside the crate, e.g. vendored
+    /// C/C++ or assembly (fewer lines is better). Complements [`Self::fully_safe_code`] by
+    /// flagging code a Rust-focused audit is unlikely to actually review.
+    fn non_rust_language_line_count(&mut self) {
+        let ProviderResult::Found(codebase_data) = &self.facts.codebase_data else {
+            return;
+        };
+        let non_rust_lines = codebase_data.non_rust_line_count();
+
+        let max_non_rust_lines = &self
+            .config
+            .non_rust_language_line_count
+            .iter()
+            .filter(|p| p.dependency_types().contains(self.dependency_type))
+            .map(|p| u64::from(p.max_count))
+            .max()
+            .unwrap_or(0);
+
+        self.apply_generic_policy(
+            Metric::NonRustLanguageLineCount,
+            &self.config.non_rust_language_line_count,
+            |p| non_rust_lines <= u64::from(p.max_count),
+            |_| format!("{non_rust_lines} lines of non-Rust source"),
+            || format!("{non_rust_lines} lines of non-Rust source (need <= {max_non_rust_lines})"),
+        );
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::NonRustLanguageLineCount, Self::ramp_down(non_rust_lines as f64, *max_non_rust_lines as f64));
     }
 
     /// Evaluate the number of transitive dependencies (fewer is better).
@@ -471,6 +1124,8 @@ impl MetricCalculator<'_> {
             |_| format!("{transitive_deps} transitive dependencies"),
             || format!("{transitive_deps} transitive dependencies (need < {max_deps})"),
         );
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::TransitiveDependencyCount, Self::ramp_down(transitive_deps as f64, *max_deps as f64));
     }
 
     /// Evaluate the number of codebase examples (more is better).
@@ -496,6 +1151,35 @@ impl MetricCalculator<'_> {
             |_| format!("{example_count} examples"),
             || format!("{example_count} examples (need >= {min_examples})"),
         );
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::ExampleCount, Self::ramp_up(example_count as f64, *min_examples as f64));
+    }
+
+    /// Evaluate the ratio of comment lines to commentable (code plus comment) lines across
+    /// the crate's source tree (more is better).
+    fn comment_ratio(&mut self) {
+        let ProviderResult::Found(codebase_data) = &self.facts.codebase_data else {
+            return;
+        };
+        let comment_ratio = codebase_data.comment_ratio() * 100.0;
+
+        let min_comment_ratio = &self
+            .config
+            .comment_ratio
+            .iter()
+            .filter(|p| p.dependency_types().contains(self.dependency_type))
+            .map(|p| f64::from(p.min_percentage))
+            .min_by(|a, b| a.partial_cmp(b).expect("percentage values should not be NaN"))
+            .unwrap_or(0.0);
+
+        self.apply_generic_policy(
+            Metric::CommentRatio,
+            &self.config.comment_ratio,
+            |p| comment_ratio >= f64::from(p.min_percentage),
+            |_| format!("{comment_ratio:.1}% comment ratio"),
+            || format!("{comment_ratio:.1}% comment ratio (need >= {min_comment_ratio:.1}%)"),
+        );
+        self.add_score(Metric::CommentRatio, Self::ramp_up(comment_ratio, *min_comment_ratio));
     }
 
     /// Evaluate the size and health of the contributor community.
@@ -522,6 +1206,8 @@ impl MetricCalculator<'_> {
             |_| format!("{value} contributors"),
             || format!("{value} contributors (need >= {min_contributors})"),
         );
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::RepoContributorCount, Self::ramp_up(value as f64, *min_contributors as f64));
     }
 
     /// Evaluate the number of repository stars.
@@ -548,6 +1234,8 @@ impl MetricCalculator<'_> {
             |_| format!("{value} stars"),
             || format!("{value} stars (need >= {min_stars})"),
         );
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::RepoStarCount, Self::ramp_up(value as f64, *min_stars as f64));
     }
 
     /// Evaluate the number of repository forks.
@@ -574,6 +1262,8 @@ impl MetricCalculator<'_> {
             |_| format!("{value} forks"),
             || format!("{value} forks (need >= {min_forks})"),
         );
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::RepoForkCount, Self::ramp_up(value as f64, *min_forks as f64));
     }
 
     /// Evaluate the number of repository subscribers/watchers.
@@ -600,6 +1290,8 @@ impl MetricCalculator<'_> {
             |_| format!("{value} subscribers"),
             || format!("{value} subscribers (need >= {min_subscribers})"),
         );
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::RepoSubscriberCount, Self::ramp_up(value as f64, *min_subscribers as f64));
     }
 
     /// Evaluate recent commit activity in the repository.
@@ -636,6 +1328,8 @@ impl MetricCalculator<'_> {
             |p| format!("{commits} commits in last {} days", p.max_days),
             || format!("{commits} commits in last {SUPPORTED_DAYS} days (need >= {min_commits})"),
         );
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::CommitActivity, Self::ramp_up(commits as f64, *min_commits as f64));
     }
 
     /// Evaluate the number of open issues (fewer is better).
@@ -662,6 +1356,8 @@ impl MetricCalculator<'_> {
             |_| format!("{value} open issues"),
             || format!("{value} open issues (need < {max_open_issues})"),
         );
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::OpenIssueCount, Self::ramp_down(value as f64, *max_open_issues as f64));
     }
 
     /// Evaluate the number of closed issues (more is better).
@@ -688,6 +1384,8 @@ impl MetricCalculator<'_> {
             |_| format!("{value} closed issues"),
             || format!("{value} closed issues (need >= {min_closed_issues})"),
         );
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::ClosedIssueCount, Self::ramp_up(value as f64, *min_closed_issues as f64));
     }
 
     /// Evaluate how quickly issues are addressed.
@@ -727,6 +1425,8 @@ impl MetricCalculator<'_> {
             |_| format!("{value} open pull requests"),
             || format!("{value} open pull requests (need < {max_open_prs})"),
         );
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::OpenPullRequestCount, Self::ramp_down(value as f64, *max_open_prs as f64));
     }
 
     /// Evaluate the number of closed pull requests (more is better).
@@ -753,6 +1453,8 @@ impl MetricCalculator<'_> {
             |_| format!("{value} closed pull requests"),
             || format!("{value} closed pull requests (need >= {min_closed_prs})"),
         );
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::ClosedPullRequestCount, Self::ramp_up(value as f64, *min_closed_prs as f64));
     }
 
     /// Evaluate how quickly pull requests are reviewed and merged.
@@ -796,13 +1498,53 @@ impl MetricCalculator<'_> {
         }
     }
 
+    /// Generic helper for graded (linearly-interpolated) policy evaluation, used in place of
+    /// [`Self::apply_generic_policy`]'s first-match-wins binary path when `metric` is opted
+    /// into [`crate::config::Config::graded_scoring_metrics`].
+    ///
+    /// Sorts the applicable policies by [`Policy::breakpoint`] and linearly interpolates the
+    /// points awarded between the two breakpoints `value` falls within — so e.g. a value
+    /// halfway between a 10-point and a 0-point tier earns ~5 points — clamping to the
+    /// nearest tier's points outside the configured range. Policies with no breakpoint, or
+    /// that don't apply to the current dependency type, are ignored.
+    fn apply_graded_policy<T, F>(&mut self, metric: Metric, policies: &[T], value: f64, info_fn: F)
+    where
+        T: Policy,
+        F: Fn(f64) -> String,
+    {
+        let mut tiers: Vec<(f64, f64)> = policies
+            .iter()
+            .filter(|p| p.dependency_types().contains(self.dependency_type))
+            .filter_map(|p| p.breakpoint().map(|breakpoint| (breakpoint, p.points())))
+            .collect();
+
+        if tiers.is_empty() {
+            self.add_not_matched(metric, "no policy defined".to_string());
+            return;
+        }
+
+        tiers.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let points = if value <= tiers[0].0 {
+            tiers[0].1
+        } else if value >= tiers[tiers.len() - 1].0 {
+            tiers[tiers.len() - 1].1
+        } else {
+            let i = tiers.partition_point(|&(breakpoint, _)| breakpoint <= value) - 1;
+            let (lower_bp, lower_pts) = tiers[i];
+            let (upper_bp, upper_pts) = tiers[i + 1];
+            let fraction = (value - lower_bp) / (upper_bp - lower_bp);
+            lower_pts + fraction * (upper_pts - lower_pts)
+        };
+
+        self.add_matched(metric, self.scale_points(metric, points), info_fn(points));
+    }
+
     /// Generic helper for responsiveness policies (checks all age percentile thresholds).
     fn apply_responsiveness_policy(&mut self, metric: Metric, policies: &[ResponsivenessPolicy], stats: &AgeStats) {
-        for policy in policies {
-            if !policy.dependency_types().contains(self.dependency_type) {
-                continue;
-            }
+        let applicable: Vec<_> = policies.iter().filter(|p| p.dependency_types().contains(self.dependency_type)).collect();
 
+        for policy in &applicable {
             if stats.avg <= policy.max_average_days
                 && stats.p50 <= policy.max_p50_days
                 && stats.p75 <= policy.max_p75_days
@@ -812,12 +1554,18 @@ impl MetricCalculator<'_> {
                 let points = self.scale_points(metric, policy.points());
 
                 self.add_matched(metric, points, "sufficiently responsive".to_string());
+                self.add_score(metric, 1.0);
                 return;
             }
         }
 
         // If no policy matched, add a single NoMatch outcome
         self.add_not_matched(metric, "insufficiently responsive".to_string());
+
+        if let Some(loosest_p50) = applicable.iter().map(|p| p.max_p50_days).max() {
+            #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+            self.add_score(metric, Self::ramp_down(stats.p50 as f64, loosest_p50 as f64));
+        }
     }
 
     /// Scale a score by applying the metric's scale factor.
@@ -836,6 +1584,70 @@ impl MetricCalculator<'_> {
         _ = self.results.insert(metric, PolicyOutcome::NoMatch(reason));
     }
 
+    /// Add a warning-level policy result to the results map
+    fn add_warning(&mut self, metric: Metric, points: f64, reason: String) {
+        _ = self.results.insert(metric, PolicyOutcome::Warning(points, reason));
+    }
+
+    /// Record a metric's continuous sub-score, weighted by the configured
+    /// [`Config::metric_weights`] (defaulting to `1.0`).
+    fn add_score(&mut self, metric: Metric, sub_score: f64) {
+        let weight = self.config.metric_weights.get(&metric).copied().unwrap_or(1.0);
+        _ = self.scores.insert(
+            metric,
+            ScoreOutcome {
+                sub_score: sub_score.clamp(0.0, 1.0),
+                weight,
+            },
+        );
+    }
+
+    /// Normalize a "more is better" value against its threshold into `[0, 1]`.
+    ///
+    /// Assumes `value` and `threshold` are both non-negative (true of every caller except
+    /// `download_trend`, which uses [`Self::ramp_trend`] instead): a non-positive threshold is
+    /// then trivially satisfied by any value, so it's unreachable rather than merely unmet.
+    fn ramp_up(value: f64, threshold: f64) -> f64 {
+        if threshold <= 0.0 { 1.0 } else { (value / threshold).clamp(0.0, 1.0) }
+    }
+
+    /// Width, in percentage points, of the ramp below [`Self::ramp_trend`]'s threshold: growth
+    /// that far under the threshold (or further) scores `0.0`, easing up to `1.0` right at the
+    /// threshold.
+    const TREND_RAMP_SPAN: f64 = 20.0;
+
+    /// Normalize a download-growth percentage against its (possibly zero or negative, since a
+    /// mild decline can be configured as tolerable) pass/fail threshold into `[0, 1]`.
+    ///
+    /// Unlike [`Self::ramp_up`], both `growth_percentage` and `threshold` can be negative here,
+    /// so dividing one by the other doesn't produce a meaningful ratio; instead this scores
+    /// `1.0` at or above `threshold` and eases down to `0.0` over [`Self::TREND_RAMP_SPAN`]
+    /// percentage points below it.
+    fn ramp_trend(growth_percentage: f64, threshold: f64) -> f64 {
+        if growth_percentage >= threshold {
+            1.0
+        } else {
+            (1.0 - (threshold - growth_percentage) / Self::TREND_RAMP_SPAN).clamp(0.0, 1.0)
+        }
+    }
+
+    /// Normalize a "fewer is better" value against its threshold into `[0, 1]`.
+    fn ramp_down(value: f64, threshold: f64) -> f64 {
+        if value <= 0.0 {
+            1.0
+        } else if threshold <= 0.0 {
+            0.0
+        } else {
+            (threshold / value).clamp(0.0, 1.0)
+        }
+    }
+
+    /// Format a byte count as a human-readable MiB figure, e.g. `1.2 MiB`.
+    #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for display formatting")]
+    fn format_mib(bytes: u64) -> String {
+        format!("{:.1} MiB", bytes as f64 / (1024.0 * 1024.0))
+    }
+
     /// Count the number of owners of a specific kind (Team or User).
     fn get_owner_count(&self, kind: OwnerKind) -> u64 {
         let ProviderResult::Found(crate_overall_data) = &self.facts.crate_overall_data else {
@@ -852,6 +1664,18 @@ impl MetricCalculator<'_> {
 
     // Advisory metrics - version-specific
 
+    /// Formats the shortest dependency chain recorded for any advisory affecting the
+    /// analyzed version, e.g. `" (myapp → tokio → vulnerable-crate)"`, or an empty string
+    /// if no dependency graph was supplied.
+    fn dependency_path_suffix(advisory_data: &AdvisoryData) -> String {
+        advisory_data
+            .records
+            .iter()
+            .filter(|r| r.affects_current_version)
+            .find_map(|r| r.dependency_path.as_ref())
+            .map_or(String::new(), |path| format!(" ({})", path.join(" → ")))
+    }
+
     /// Evaluate total vulnerability count for this version
     fn vulnerability_count(&mut self) {
         let ProviderResult::Found(advisory_data) = &self.facts.advisory_data else {
@@ -859,14 +1683,27 @@ impl MetricCalculator<'_> {
         };
         let count = advisory_data.vulnerability_count;
         let max_count = self.get_max_count(&self.config.vulnerability_count);
-
-        self.apply_generic_policy(
-            Metric::VulnerabilityCount,
-            &self.config.vulnerability_count,
-            |p| count <= u64::from(p.max_count),
-            |_| format!("{count} vulnerabilities"),
-            || format!("{count} vulnerabilities (need <= {max_count})"),
-        );
+        let path_suffix = Self::dependency_path_suffix(advisory_data);
+
+        if self.config.graded_scoring_metrics.contains(&Metric::VulnerabilityCount) {
+            #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+            self.apply_graded_policy(
+                Metric::VulnerabilityCount,
+                &self.config.vulnerability_count,
+                count as f64,
+                |points| format!("{count} vulnerabilities{path_suffix} ({points:.1} pts)"),
+            );
+        } else {
+            self.apply_generic_policy(
+                Metric::VulnerabilityCount,
+                &self.config.vulnerability_count,
+                |p| count <= u64::from(p.max_count),
+                |_| format!("{count} vulnerabilities{path_suffix}"),
+                || format!("{count} vulnerabilities (need <= {max_count}){path_suffix}"),
+            );
+        }
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::VulnerabilityCount, Self::ramp_down(count as f64, max_count as f64));
     }
 
     /// Evaluate low severity vulnerability count for this version
@@ -876,14 +1713,27 @@ impl MetricCalculator<'_> {
         };
         let count = advisory_data.low_vulnerability_count;
         let max_count = self.get_max_count(&self.config.low_vulnerability_count);
-
-        self.apply_generic_policy(
-            Metric::LowVulnerabilityCount,
-            &self.config.low_vulnerability_count,
-            |p| count <= u64::from(p.max_count),
-            |_| format!("{count} low severity vulnerabilities"),
-            || format!("{count} low severity vulnerabilities (need <= {max_count})"),
-        );
+        let path_suffix = Self::dependency_path_suffix(advisory_data);
+
+        if self.config.graded_scoring_metrics.contains(&Metric::LowVulnerabilityCount) {
+            #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+            self.apply_graded_policy(
+                Metric::LowVulnerabilityCount,
+                &self.config.low_vulnerability_count,
+                count as f64,
+                |points| format!("{count} low severity vulnerabilities{path_suffix} ({points:.1} pts)"),
+            );
+        } else {
+            self.apply_generic_policy(
+                Metric::LowVulnerabilityCount,
+                &self.config.low_vulnerability_count,
+                |p| count <= u64::from(p.max_count),
+                |_| format!("{count} low severity vulnerabilities{path_suffix}"),
+                || format!("{count} low severity vulnerabilities (need <= {max_count}){path_suffix}"),
+            );
+        }
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::LowVulnerabilityCount, Self::ramp_down(count as f64, max_count as f64));
     }
 
     /// Evaluate medium severity vulnerability count for this version
@@ -893,14 +1743,27 @@ impl MetricCalculator<'_> {
         };
         let count = advisory_data.medium_vulnerability_count;
         let max_count = self.get_max_count(&self.config.medium_vulnerability_count);
-
-        self.apply_generic_policy(
-            Metric::MediumVulnerabilityCount,
-            &self.config.medium_vulnerability_count,
-            |p| count <= u64::from(p.max_count),
-            |_| format!("{count} medium severity vulnerabilities"),
-            || format!("{count} medium severity vulnerabilities (need <= {max_count})"),
-        );
+        let path_suffix = Self::dependency_path_suffix(advisory_data);
+
+        if self.config.graded_scoring_metrics.contains(&Metric::MediumVulnerabilityCount) {
+            #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+            self.apply_graded_policy(
+                Metric::MediumVulnerabilityCount,
+                &self.config.medium_vulnerability_count,
+                count as f64,
+                |points| format!("{count} medium severity vulnerabilities{path_suffix} ({points:.1} pts)"),
+            );
+        } else {
+            self.apply_generic_policy(
+                Metric::MediumVulnerabilityCount,
+                &self.config.medium_vulnerability_count,
+                |p| count <= u64::from(p.max_count),
+                |_| format!("{count} medium severity vulnerabilities{path_suffix}"),
+                || format!("{count} medium severity vulnerabilities (need <= {max_count}){path_suffix}"),
+            );
+        }
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::MediumVulnerabilityCount, Self::ramp_down(count as f64, max_count as f64));
     }
 
     /// Evaluate high severity vulnerability count for this version
@@ -910,14 +1773,27 @@ impl MetricCalculator<'_> {
         };
         let count = advisory_data.high_vulnerability_count;
         let max_count = self.get_max_count(&self.config.high_vulnerability_count);
-
-        self.apply_generic_policy(
-            Metric::HighVulnerabilityCount,
-            &self.config.high_vulnerability_count,
-            |p| count <= u64::from(p.max_count),
-            |_| format!("{count} high severity vulnerabilities"),
-            || format!("{count} high severity vulnerabilities (need <= {max_count})"),
-        );
+        let path_suffix = Self::dependency_path_suffix(advisory_data);
+
+        if self.config.graded_scoring_metrics.contains(&Metric::HighVulnerabilityCount) {
+            #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+            self.apply_graded_policy(
+                Metric::HighVulnerabilityCount,
+                &self.config.high_vulnerability_count,
+                count as f64,
+                |points| format!("{count} high severity vulnerabilities{path_suffix} ({points:.1} pts)"),
+            );
+        } else {
+            self.apply_generic_policy(
+                Metric::HighVulnerabilityCount,
+                &self.config.high_vulnerability_count,
+                |p| count <= u64::from(p.max_count),
+                |_| format!("{count} high severity vulnerabilities{path_suffix}"),
+                || format!("{count} high severity vulnerabilities (need <= {max_count}){path_suffix}"),
+            );
+        }
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::HighVulnerabilityCount, Self::ramp_down(count as f64, max_count as f64));
     }
 
     /// Evaluate critical severity vulnerability count for this version
@@ -928,13 +1804,56 @@ impl MetricCalculator<'_> {
         let count = advisory_data.critical_vulnerability_count;
         let max_count = self.get_max_count(&self.config.critical_vulnerability_count);
 
-        self.apply_generic_policy(
-            Metric::CriticalVulnerabilityCount,
-            &self.config.critical_vulnerability_count,
-            |p| count <= u64::from(p.max_count),
-            |_| format!("{count} critical severity vulnerabilities"),
-            || format!("{count} critical severity vulnerabilities (need <= {max_count})"),
-        );
+        if self.config.graded_scoring_metrics.contains(&Metric::CriticalVulnerabilityCount) {
+            #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+            self.apply_graded_policy(
+                Metric::CriticalVulnerabilityCount,
+                &self.config.critical_vulnerability_count,
+                count as f64,
+                |points| format!("{count} critical severity vulnerabilities ({points:.1} pts)"),
+            );
+        } else {
+            self.apply_generic_policy(
+                Metric::CriticalVulnerabilityCount,
+                &self.config.critical_vulnerability_count,
+                |p| count <= u64::from(p.max_count),
+                |_| format!("{count} critical severity vulnerabilities"),
+                || format!("{count} critical severity vulnerabilities (need <= {max_count})"),
+            );
+        }
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::CriticalVulnerabilityCount, Self::ramp_down(count as f64, max_count as f64));
+    }
+
+    /// Evaluate the CVSS-weighted vulnerability score for this version: the sum of each
+    /// affecting vulnerability's base score, re-derived from its CVSS vector rather than its
+    /// coarse severity bucket.
+    fn cvss_weighted_vulnerability_score(&mut self) {
+        let ProviderResult::Found(advisory_data) = &self.facts.advisory_data else {
+            return;
+        };
+        let score = advisory_data.cvss_weighted_vulnerability_score;
+        let parse_failures = advisory_data.cvss_parse_failures;
+        let max_score = self.get_max_score(&self.config.cvss_weighted_vulnerability_score);
+
+        if parse_failures > 0 {
+            self.add_not_matched(
+                Metric::CvssWeightedVulnerabilityScore,
+                format!(
+                    "{parse_failures} vulnerabilities have a missing or unparseable CVSS vector; \
+                     falling back to the bucketed severity counts for them"
+                ),
+            );
+        } else {
+            self.apply_generic_policy(
+                Metric::CvssWeightedVulnerabilityScore,
+                &self.config.cvss_weighted_vulnerability_score,
+                |p| score <= p.max_score,
+                |_| format!("CVSS-weighted score {score:.1}"),
+                || format!("CVSS-weighted score {score:.1} (need <= {max_score:.1})"),
+            );
+        }
+        self.add_score(Metric::CvssWeightedVulnerabilityScore, Self::ramp_down(score, max_score));
     }
 
     /// Evaluate total warning count for this version
@@ -945,13 +1864,25 @@ impl MetricCalculator<'_> {
         let count = advisory_data.warning_count;
         let max_count = self.get_max_count(&self.config.warning_count);
 
-        self.apply_generic_policy(
-            Metric::WarningCount,
-            &self.config.warning_count,
-            |p| count <= u64::from(p.max_count),
-            |_| format!("{count} warnings"),
-            || format!("{count} warnings (need <= {max_count})"),
-        );
+        if self.config.graded_scoring_metrics.contains(&Metric::WarningCount) {
+            #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+            self.apply_graded_policy(
+                Metric::WarningCount,
+                &self.config.warning_count,
+                count as f64,
+                |points| format!("{count} warnings ({points:.1} pts)"),
+            );
+        } else {
+            self.apply_generic_policy(
+                Metric::WarningCount,
+                &self.config.warning_count,
+                |p| count <= u64::from(p.max_count),
+                |_| format!("{count} warnings"),
+                || format!("{count} warnings (need <= {max_count})"),
+            );
+        }
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::WarningCount, Self::ramp_down(count as f64, max_count as f64));
     }
 
     /// Evaluate notice warning count for this version
@@ -962,13 +1893,25 @@ impl MetricCalculator<'_> {
         let count = advisory_data.notice_warning_count;
         let max_count = self.get_max_count(&self.config.notice_warning_count);
 
-        self.apply_generic_policy(
-            Metric::NoticeWarningCount,
-            &self.config.notice_warning_count,
-            |p| count <= u64::from(p.max_count),
-            |_| format!("{count} notice warnings"),
-            || format!("{count} notice warnings (need <= {max_count})"),
-        );
+        if self.config.graded_scoring_metrics.contains(&Metric::NoticeWarningCount) {
+            #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+            self.apply_graded_policy(
+                Metric::NoticeWarningCount,
+                &self.config.notice_warning_count,
+                count as f64,
+                |points| format!("{count} notice warnings ({points:.1} pts)"),
+            );
+        } else {
+            self.apply_generic_policy(
+                Metric::NoticeWarningCount,
+                &self.config.notice_warning_count,
+                |p| count <= u64::from(p.max_count),
+                |_| format!("{count} notice warnings"),
+                || format!("{count} notice warnings (need <= {max_count})"),
+            );
+        }
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::NoticeWarningCount, Self::ramp_down(count as f64, max_count as f64));
     }
 
     /// Evaluate unmaintained warning count for this version
@@ -979,13 +1922,25 @@ impl MetricCalculator<'_> {
         let count = advisory_data.unmaintained_warning_count;
         let max_count = self.get_max_count(&self.config.unmaintained_warning_count);
 
-        self.apply_generic_policy(
-            Metric::UnmaintainedWarningCount,
-            &self.config.unmaintained_warning_count,
-            |p| count <= u64::from(p.max_count),
-            |_| format!("{count} unmaintained warnings"),
-            || format!("{count} unmaintained warnings (need <= {max_count})"),
-        );
+        if self.config.graded_scoring_metrics.contains(&Metric::UnmaintainedWarningCount) {
+            #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+            self.apply_graded_policy(
+                Metric::UnmaintainedWarningCount,
+                &self.config.unmaintained_warning_count,
+                count as f64,
+                |points| format!("{count} unmaintained warnings ({points:.1} pts)"),
+            );
+        } else {
+            self.apply_generic_policy(
+                Metric::UnmaintainedWarningCount,
+                &self.config.unmaintained_warning_count,
+                |p| count <= u64::from(p.max_count),
+                |_| format!("{count} unmaintained warnings"),
+                || format!("{count} unmaintained warnings (need <= {max_count})"),
+            );
+        }
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::UnmaintainedWarningCount, Self::ramp_down(count as f64, max_count as f64));
     }
 
     /// Evaluate unsound warning count for this version
@@ -996,13 +1951,25 @@ impl MetricCalculator<'_> {
         let count = advisory_data.unsound_warning_count;
         let max_count = self.get_max_count(&self.config.unsound_warning_count);
 
-        self.apply_generic_policy(
-            Metric::UnsoundWarningCount,
-            &self.config.unsound_warning_count,
-            |p| count <= u64::from(p.max_count),
-            |_| format!("{count} unsound warnings"),
-            || format!("{count} unsound warnings (need <= {max_count})"),
-        );
+        if self.config.graded_scoring_metrics.contains(&Metric::UnsoundWarningCount) {
+            #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+            self.apply_graded_policy(
+                Metric::UnsoundWarningCount,
+                &self.config.unsound_warning_count,
+                count as f64,
+                |points| format!("{count} unsound warnings ({points:.1} pts)"),
+            );
+        } else {
+            self.apply_generic_policy(
+                Metric::UnsoundWarningCount,
+                &self.config.unsound_warning_count,
+                |p| count <= u64::from(p.max_count),
+                |_| format!("{count} unsound warnings"),
+                || format!("{count} unsound warnings (need <= {max_count})"),
+            );
+        }
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::UnsoundWarningCount, Self::ramp_down(count as f64, max_count as f64));
     }
 
     /// Evaluate yanked warning count for this version
@@ -1013,13 +1980,25 @@ impl MetricCalculator<'_> {
         let count = advisory_data.yanked_warning_count;
         let max_count = self.get_max_count(&self.config.yanked_warning_count);
 
-        self.apply_generic_policy(
-            Metric::YankedWarningCount,
-            &self.config.yanked_warning_count,
-            |p| count <= u64::from(p.max_count),
-            |_| format!("{count} yanked warnings"),
-            || format!("{count} yanked warnings (need <= {max_count})"),
-        );
+        if self.config.graded_scoring_metrics.contains(&Metric::YankedWarningCount) {
+            #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+            self.apply_graded_policy(
+                Metric::YankedWarningCount,
+                &self.config.yanked_warning_count,
+                count as f64,
+                |points| format!("{count} yanked warnings ({points:.1} pts)"),
+            );
+        } else {
+            self.apply_generic_policy(
+                Metric::YankedWarningCount,
+                &self.config.yanked_warning_count,
+                |p| count <= u64::from(p.max_count),
+                |_| format!("{count} yanked warnings"),
+                || format!("{count} yanked warnings (need <= {max_count})"),
+            );
+        }
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::YankedWarningCount, Self::ramp_down(count as f64, max_count as f64));
     }
 
     // Advisory metrics - historical (all versions)
@@ -1032,13 +2011,25 @@ impl MetricCalculator<'_> {
         let count = advisory_data.historical_vulnerability_count;
         let max_count = self.get_max_count(&self.config.historical_vulnerability_count);
 
-        self.apply_generic_policy(
-            Metric::HistoricalVulnerabilityCount,
-            &self.config.historical_vulnerability_count,
-            |p| count <= u64::from(p.max_count),
-            |_| format!("{count} historical vulnerabilities"),
-            || format!("{count} historical vulnerabilities (need <= {max_count})"),
-        );
+        if self.config.graded_scoring_metrics.contains(&Metric::HistoricalVulnerabilityCount) {
+            #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+            self.apply_graded_policy(
+                Metric::HistoricalVulnerabilityCount,
+                &self.config.historical_vulnerability_count,
+                count as f64,
+                |points| format!("{count} historical vulnerabilities ({points:.1} pts)"),
+            );
+        } else {
+            self.apply_generic_policy(
+                Metric::HistoricalVulnerabilityCount,
+                &self.config.historical_vulnerability_count,
+                |p| count <= u64::from(p.max_count),
+                |_| format!("{count} historical vulnerabilities"),
+                || format!("{count} historical vulnerabilities (need <= {max_count})"),
+            );
+        }
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::HistoricalVulnerabilityCount, Self::ramp_down(count as f64, max_count as f64));
     }
 
     /// Evaluate historical low severity vulnerability count
@@ -1049,13 +2040,25 @@ impl MetricCalculator<'_> {
         let count = advisory_data.historical_low_vulnerability_count;
         let max_count = self.get_max_count(&self.config.historical_low_vulnerability_count);
 
-        self.apply_generic_policy(
-            Metric::HistoricalLowVulnerabilityCount,
-            &self.config.historical_low_vulnerability_count,
-            |p| count <= u64::from(p.max_count),
-            |_| format!("{count} historical low severity vulnerabilities"),
-            || format!("{count} historical low severity vulnerabilities (need <= {max_count})"),
-        );
+        if self.config.graded_scoring_metrics.contains(&Metric::HistoricalLowVulnerabilityCount) {
+            #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+            self.apply_graded_policy(
+                Metric::HistoricalLowVulnerabilityCount,
+                &self.config.historical_low_vulnerability_count,
+                count as f64,
+                |points| format!("{count} historical low severity vulnerabilities ({points:.1} pts)"),
+            );
+        } else {
+            self.apply_generic_policy(
+                Metric::HistoricalLowVulnerabilityCount,
+                &self.config.historical_low_vulnerability_count,
+                |p| count <= u64::from(p.max_count),
+                |_| format!("{count} historical low severity vulnerabilities"),
+                || format!("{count} historical low severity vulnerabilities (need <= {max_count})"),
+            );
+        }
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::HistoricalLowVulnerabilityCount, Self::ramp_down(count as f64, max_count as f64));
     }
 
     /// Evaluate historical medium severity vulnerability count
@@ -1066,13 +2069,25 @@ impl MetricCalculator<'_> {
         let count = advisory_data.historical_medium_vulnerability_count;
         let max_count = self.get_max_count(&self.config.historical_medium_vulnerability_count);
 
-        self.apply_generic_policy(
-            Metric::HistoricalMediumVulnerabilityCount,
-            &self.config.historical_medium_vulnerability_count,
-            |p| count <= u64::from(p.max_count),
-            |_| format!("{count} historical medium severity vulnerabilities"),
-            || format!("{count} historical medium severity vulnerabilities (need <= {max_count})"),
-        );
+        if self.config.graded_scoring_metrics.contains(&Metric::HistoricalMediumVulnerabilityCount) {
+            #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+            self.apply_graded_policy(
+                Metric::HistoricalMediumVulnerabilityCount,
+                &self.config.historical_medium_vulnerability_count,
+                count as f64,
+                |points| format!("{count} historical medium severity vulnerabilities ({points:.1} pts)"),
+            );
+        } else {
+            self.apply_generic_policy(
+                Metric::HistoricalMediumVulnerabilityCount,
+                &self.config.historical_medium_vulnerability_count,
+                |p| count <= u64::from(p.max_count),
+                |_| format!("{count} historical medium severity vulnerabilities"),
+                || format!("{count} historical medium severity vulnerabilities (need <= {max_count})"),
+            );
+        }
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::HistoricalMediumVulnerabilityCount, Self::ramp_down(count as f64, max_count as f64));
     }
 
     /// Evaluate historical high severity vulnerability count
@@ -1083,13 +2098,25 @@ impl MetricCalculator<'_> {
         let count = advisory_data.historical_high_vulnerability_count;
         let max_count = self.get_max_count(&self.config.historical_high_vulnerability_count);
 
-        self.apply_generic_policy(
-            Metric::HistoricalHighVulnerabilityCount,
-            &self.config.historical_high_vulnerability_count,
-            |p| count <= u64::from(p.max_count),
-            |_| format!("{count} historical high severity vulnerabilities"),
-            || format!("{count} historical high severity vulnerabilities (need <= {max_count})"),
-        );
+        if self.config.graded_scoring_metrics.contains(&Metric::HistoricalHighVulnerabilityCount) {
+            #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+            self.apply_graded_policy(
+                Metric::HistoricalHighVulnerabilityCount,
+                &self.config.historical_high_vulnerability_count,
+                count as f64,
+                |points| format!("{count} historical high severity vulnerabilities ({points:.1} pts)"),
+            );
+        } else {
+            self.apply_generic_policy(
+                Metric::HistoricalHighVulnerabilityCount,
+                &self.config.historical_high_vulnerability_count,
+                |p| count <= u64::from(p.max_count),
+                |_| format!("{count} historical high severity vulnerabilities"),
+                || format!("{count} historical high severity vulnerabilities (need <= {max_count})"),
+            );
+        }
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::HistoricalHighVulnerabilityCount, Self::ramp_down(count as f64, max_count as f64));
     }
 
     /// Evaluate historical critical severity vulnerability count
@@ -1100,13 +2127,54 @@ impl MetricCalculator<'_> {
         let count = advisory_data.historical_critical_vulnerability_count;
         let max_count = self.get_max_count(&self.config.historical_critical_vulnerability_count);
 
-        self.apply_generic_policy(
-            Metric::HistoricalCriticalVulnerabilityCount,
-            &self.config.historical_critical_vulnerability_count,
-            |p| count <= u64::from(p.max_count),
-            |_| format!("{count} historical critical severity vulnerabilities"),
-            || format!("{count} historical critical severity vulnerabilities (need <= {max_count})"),
-        );
+        if self.config.graded_scoring_metrics.contains(&Metric::HistoricalCriticalVulnerabilityCount) {
+            #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+            self.apply_graded_policy(
+                Metric::HistoricalCriticalVulnerabilityCount,
+                &self.config.historical_critical_vulnerability_count,
+                count as f64,
+                |points| format!("{count} historical critical severity vulnerabilities ({points:.1} pts)"),
+            );
+        } else {
+            self.apply_generic_policy(
+                Metric::HistoricalCriticalVulnerabilityCount,
+                &self.config.historical_critical_vulnerability_count,
+                |p| count <= u64::from(p.max_count),
+                |_| format!("{count} historical critical severity vulnerabilities"),
+                || format!("{count} historical critical severity vulnerabilities (need <= {max_count})"),
+            );
+        }
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::HistoricalCriticalVulnerabilityCount, Self::ramp_down(count as f64, max_count as f64));
+    }
+
+    /// Evaluate the CVSS-weighted vulnerability score across the crate's entire history.
+    fn historical_cvss_weighted_vulnerability_score(&mut self) {
+        let ProviderResult::Found(advisory_data) = &self.facts.advisory_data else {
+            return;
+        };
+        let score = advisory_data.historical_cvss_weighted_vulnerability_score;
+        let parse_failures = advisory_data.historical_cvss_parse_failures;
+        let max_score = self.get_max_score(&self.config.historical_cvss_weighted_vulnerability_score);
+
+        if parse_failures > 0 {
+            self.add_not_matched(
+                Metric::HistoricalCvssWeightedVulnerabilityScore,
+                format!(
+                    "{parse_failures} historical vulnerabilities have a missing or unparseable CVSS vector; \
+                     falling back to the bucketed severity counts for them"
+                ),
+            );
+        } else {
+            self.apply_generic_policy(
+                Metric::HistoricalCvssWeightedVulnerabilityScore,
+                &self.config.historical_cvss_weighted_vulnerability_score,
+                |p| score <= p.max_score,
+                |_| format!("historical CVSS-weighted score {score:.1}"),
+                || format!("historical CVSS-weighted score {score:.1} (need <= {max_score:.1})"),
+            );
+        }
+        self.add_score(Metric::HistoricalCvssWeightedVulnerabilityScore, Self::ramp_down(score, max_score));
     }
 
     /// Evaluate historical warning count
@@ -1117,13 +2185,25 @@ impl MetricCalculator<'_> {
         let count = advisory_data.historical_warning_count;
         let max_count = self.get_max_count(&self.config.historical_warning_count);
 
-        self.apply_generic_policy(
-            Metric::HistoricalWarningCount,
-            &self.config.historical_warning_count,
-            |p| count <= u64::from(p.max_count),
-            |_| format!("{count} historical warnings"),
-            || format!("{count} historical warnings (need <= {max_count})"),
-        );
+        if self.config.graded_scoring_metrics.contains(&Metric::HistoricalWarningCount) {
+            #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+            self.apply_graded_policy(
+                Metric::HistoricalWarningCount,
+                &self.config.historical_warning_count,
+                count as f64,
+                |points| format!("{count} historical warnings ({points:.1} pts)"),
+            );
+        } else {
+            self.apply_generic_policy(
+                Metric::HistoricalWarningCount,
+                &self.config.historical_warning_count,
+                |p| count <= u64::from(p.max_count),
+                |_| format!("{count} historical warnings"),
+                || format!("{count} historical warnings (need <= {max_count})"),
+            );
+        }
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::HistoricalWarningCount, Self::ramp_down(count as f64, max_count as f64));
     }
 
     /// Evaluate historical notice warning count
@@ -1134,13 +2214,25 @@ impl MetricCalculator<'_> {
         let count = advisory_data.historical_notice_warning_count;
         let max_count = self.get_max_count(&self.config.historical_notice_warning_count);
 
-        self.apply_generic_policy(
-            Metric::HistoricalNoticeWarningCount,
-            &self.config.historical_notice_warning_count,
-            |p| count <= u64::from(p.max_count),
-            |_| format!("{count} historical notice warnings"),
-            || format!("{count} historical notice warnings (need <= {max_count})"),
-        );
+        if self.config.graded_scoring_metrics.contains(&Metric::HistoricalNoticeWarningCount) {
+            #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+            self.apply_graded_policy(
+                Metric::HistoricalNoticeWarningCount,
+                &self.config.historical_notice_warning_count,
+                count as f64,
+                |points| format!("{count} historical notice warnings ({points:.1} pts)"),
+            );
+        } else {
+            self.apply_generic_policy(
+                Metric::HistoricalNoticeWarningCount,
+                &self.config.historical_notice_warning_count,
+                |p| count <= u64::from(p.max_count),
+                |_| format!("{count} historical notice warnings"),
+                || format!("{count} historical notice warnings (need <= {max_count})"),
+            );
+        }
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::HistoricalNoticeWarningCount, Self::ramp_down(count as f64, max_count as f64));
     }
 
     /// Evaluate historical unmaintained warning count
@@ -1151,13 +2243,25 @@ impl MetricCalculator<'_> {
         let count = advisory_data.historical_unmaintained_warning_count;
         let max_count = self.get_max_count(&self.config.historical_unmaintained_warning_count);
 
-        self.apply_generic_policy(
-            Metric::HistoricalUnmaintainedWarningCount,
-            &self.config.historical_unmaintained_warning_count,
-            |p| count <= u64::from(p.max_count),
-            |_| format!("{count} historical unmaintained warnings"),
-            || format!("{count} historical unmaintained warnings (need <= {max_count})"),
-        );
+        if self.config.graded_scoring_metrics.contains(&Metric::HistoricalUnmaintainedWarningCount) {
+            #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+            self.apply_graded_policy(
+                Metric::HistoricalUnmaintainedWarningCount,
+                &self.config.historical_unmaintained_warning_count,
+                count as f64,
+                |points| format!("{count} historical unmaintained warnings ({points:.1} pts)"),
+            );
+        } else {
+            self.apply_generic_policy(
+                Metric::HistoricalUnmaintainedWarningCount,
+                &self.config.historical_unmaintained_warning_count,
+                |p| count <= u64::from(p.max_count),
+                |_| format!("{count} historical unmaintained warnings"),
+                || format!("{count} historical unmaintained warnings (need <= {max_count})"),
+            );
+        }
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::HistoricalUnmaintainedWarningCount, Self::ramp_down(count as f64, max_count as f64));
     }
 
     /// Evaluate historical unsound warning count
@@ -1168,13 +2272,25 @@ impl MetricCalculator<'_> {
         let count = advisory_data.historical_unsound_warning_count;
         let max_count = self.get_max_count(&self.config.historical_unsound_warning_count);
 
-        self.apply_generic_policy(
-            Metric::HistoricalUnsoundWarningCount,
-            &self.config.historical_unsound_warning_count,
-            |p| count <= u64::from(p.max_count),
-            |_| format!("{count} historical unsound warnings"),
-            || format!("{count} historical unsound warnings (need <= {max_count})"),
-        );
+        if self.config.graded_scoring_metrics.contains(&Metric::HistoricalUnsoundWarningCount) {
+            #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+            self.apply_graded_policy(
+                Metric::HistoricalUnsoundWarningCount,
+                &self.config.historical_unsound_warning_count,
+                count as f64,
+                |points| format!("{count} historical unsound warnings ({points:.1} pts)"),
+            );
+        } else {
+            self.apply_generic_policy(
+                Metric::HistoricalUnsoundWarningCount,
+                &self.config.historical_unsound_warning_count,
+                |p| count <= u64::from(p.max_count),
+                |_| format!("{count} historical unsound warnings"),
+                || format!("{count} historical unsound warnings (need <= {max_count})"),
+            );
+        }
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::HistoricalUnsoundWarningCount, Self::ramp_down(count as f64, max_count as f64));
     }
 
     /// Evaluate historical yanked warning count
@@ -1185,12 +2301,37 @@ impl MetricCalculator<'_> {
         let count = advisory_data.historical_yanked_warning_count;
         let max_count = self.get_max_count(&self.config.historical_yanked_warning_count);
 
-        self.apply_generic_policy(
-            Metric::HistoricalYankedWarningCount,
-            &self.config.historical_yanked_warning_count,
-            |p| count <= u64::from(p.max_count),
-            |_| format!("{count} historical yanked warnings"),
-            || format!("{count} historical yanked warnings (need <= {max_count})"),
+        if self.config.graded_scoring_metrics.contains(&Metric::HistoricalYankedWarningCount) {
+            #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+            self.apply_graded_policy(
+                Metric::HistoricalYankedWarningCount,
+                &self.config.historical_yanked_warning_count,
+                count as f64,
+                |points| format!("{count} historical yanked warnings ({points:.1} pts)"),
+            );
+        } else {
+            self.apply_generic_policy(
+                Metric::HistoricalYankedWarningCount,
+                &self.config.historical_yanked_warning_count,
+                |p| count <= u64::from(p.max_count),
+                |_| format!("{count} historical yanked warnings"),
+                || format!("{count} historical yanked warnings (need <= {max_count})"),
+            );
+        }
+        #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
+        self.add_score(Metric::HistoricalYankedWarningCount, Self::ramp_down(count as f64, max_count as f64));
+    }
+
+    /// Evaluate how quickly past vulnerabilities in this crate have historically been patched.
+    fn advisory_patch_responsiveness(&mut self) {
+        let ProviderResult::Found(advisory_data) = &self.facts.advisory_data else {
+            return;
+        };
+
+        self.apply_responsiveness_policy(
+            Metric::AdvisoryPatchResponsiveness,
+            &self.config.advisory_patch_responsiveness,
+            &advisory_data.patch_responsiveness,
         );
     }
 
@@ -1203,6 +2344,25 @@ impl MetricCalculator<'_> {
             .max()
             .unwrap_or(0)
     }
+
+    /// Helper to get the maximum score from a `MaxScorePolicy` vector
+    fn get_max_score(&self, policies: &[crate::config::MaxScorePolicy]) -> f64 {
+        policies
+            .iter()
+            .filter(|p| p.dependency_types().contains(self.dependency_type))
+            .map(|p| p.max_score)
+            .fold(0.0, f64::max)
+    }
+
+    /// Helper to get the minimum score from a `MinScorePolicy` vector
+    fn get_min_score(&self, policies: &[crate::config::MinScorePolicy]) -> f64 {
+        let min = policies
+            .iter()
+            .filter(|p| p.dependency_types().contains(self.dependency_type))
+            .map(|p| p.min_score)
+            .fold(f64::INFINITY, f64::min);
+        if min.is_finite() { min } else { 0.0 }
+    }
 }
 
 /// Check if the current version was released within the specified number of days.
@@ -1,6 +1,14 @@
 mod metric_calculator;
 mod policy_outcome;
 mod ranker;
+mod risk_level;
+pub mod script_engine;
+mod score_outcome;
+mod weighted_score;
 
 pub use policy_outcome::PolicyOutcome;
 pub use ranker::{Ranker, RankingOutcome, extract_reasons};
+pub use risk_level::RiskLevel;
+pub use script_engine::ScriptEngine;
+pub use score_outcome::ScoreOutcome;
+pub use weighted_score::{AggregationMode, aggregate_score};
@@ -1,11 +1,18 @@
 //! Policy evaluation result type
 
+use serde::{Deserialize, Serialize};
+
 /// Result of evaluating a policy
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum PolicyOutcome {
     /// Policy evaluation matched with the given points and information about the matching policy
     Match(f64, String),
 
+    /// Policy evaluation didn't strictly match, but the policy treats this as advisory
+    /// rather than disqualifying; carries the given points (often `0.0`) and a reason
+    Warning(f64, String),
+
     /// Policy evaluation didn't match with the given reason
     NoMatch(String),
 }
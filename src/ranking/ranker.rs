@@ -1,10 +1,10 @@
 //! Scoring logic for evaluating crate quality.
 
 use crate::config::Config;
-use crate::facts::CrateFacts;
+use crate::facts::{CrateFacts, ProviderResult};
 use crate::metrics::{Metric, MetricCategory};
 use crate::misc::DependencyType;
-use crate::ranking::{PolicyOutcome, metric_calculator};
+use crate::ranking::{AggregationMode, PolicyOutcome, RiskLevel, ScoreOutcome, ScriptEngine, aggregate_score, metric_calculator};
 use core::cell::RefCell;
 use core::hash::BuildHasher;
 use std::collections::HashMap;
@@ -15,34 +15,73 @@ pub struct RankingOutcome {
     pub overall_score: f64,
     pub category_scores: HashMap<MetricCategory, f64>,
     pub details: HashMap<Metric, PolicyOutcome>,
+
+    /// Outcome of every applicable `Config::custom_metrics` script, keyed by
+    /// [`crate::config::ScriptPolicy::name`]. Folded into `overall_score` and
+    /// `category_scores[MetricCategory::Custom]` alongside `details`, but kept separate since
+    /// scripts aren't tied to a fixed [`Metric`].
+    pub custom_details: HashMap<String, PolicyOutcome>,
+
+    /// Continuous health score in `[0, 100]`, combining every metric's [`ScoreOutcome`] with
+    /// the ranker's configured [`AggregationMode`]. Complements `overall_score`'s
+    /// pass/fail-derived average so crates can be ranked/sorted rather than only gated.
+    pub health_score: f64,
+    pub scores: HashMap<Metric, ScoreOutcome>,
     pub dependency_type: DependencyType,
+
+    /// Where `health_score` falls against `Config::medium_risk_threshold`/
+    /// `Config::low_risk_threshold`, per [`Config::classify_risk`]. `None` when the crate's
+    /// name or version couldn't be determined from `facts` (a [`ProviderResult::CrateNotFound`]
+    /// or [`ProviderResult::Error`] for `crate_overall_data`/`crate_version_data`).
+    pub risk_level: Option<RiskLevel>,
 }
 
 /// Ranker evaluates crate quality based on configured policies
 #[derive(Debug)]
 pub struct Ranker<'a> {
     config: &'a Config,
+    aggregation_mode: AggregationMode,
     policy_outcomes: RefCell<HashMap<Metric, PolicyOutcome>>,
+    score_outcomes: RefCell<HashMap<Metric, ScoreOutcome>>,
+    script_engine: ScriptEngine,
 }
 
 impl<'a> Ranker<'a> {
-    /// Create a new ranker with the given configuration
+    /// Create a new ranker with the given configuration, combining sub-scores with a
+    /// weighted arithmetic mean.
     #[must_use]
     pub fn new(config: &'a Config) -> Self {
+        Self::with_aggregation_mode(config, AggregationMode::WeightedMean)
+    }
+
+    /// Create a new ranker that combines sub-scores with the given [`AggregationMode`].
+    ///
+    /// Compiles `config.custom_metrics` once, up front, so per-crate ranking only has to
+    /// re-evaluate the already-parsed scripts.
+    #[must_use]
+    pub fn with_aggregation_mode(config: &'a Config, aggregation_mode: AggregationMode) -> Self {
         Self {
             config,
+            aggregation_mode,
             policy_outcomes: RefCell::new(HashMap::new()),
+            score_outcomes: RefCell::new(HashMap::new()),
+            script_engine: ScriptEngine::compile(config),
         }
     }
 
     /// Rank a crate based on multiple quality criteria
     pub fn rank(&self, facts: &CrateFacts, dependency_type: DependencyType) -> RankingOutcome {
         let mut policy_outcomes = self.policy_outcomes.borrow_mut();
+        let mut score_outcomes = self.score_outcomes.borrow_mut();
 
         policy_outcomes.clear();
-        metric_calculator::calculate(self.config, facts, dependency_type, &mut policy_outcomes);
+        score_outcomes.clear();
+        metric_calculator::calculate(self.config, facts, dependency_type, &mut policy_outcomes, &mut score_outcomes);
+
+        let custom_outcomes = self.script_engine.evaluate(facts, dependency_type);
 
         let mut total_points = 0.0;
+        let mut total_count = 0usize;
         let mut category_points: HashMap<MetricCategory, f64> = HashMap::new();
         let mut category_counts: HashMap<MetricCategory, usize> = HashMap::new();
 
@@ -50,18 +89,32 @@ impl<'a> Ranker<'a> {
             let category = metric.category();
             let points = match outcome {
                 PolicyOutcome::Match(points, _info) => *points,
+                PolicyOutcome::Warning(points, _reason) => *points,
                 PolicyOutcome::NoMatch(_reason) => 0.0,
             };
             total_points += points;
+            total_count += 1;
             *category_points.entry(category).or_insert(0.0) += points;
             *category_counts.entry(category).or_insert(0) += 1;
         }
 
-        let score = if policy_outcomes.is_empty() {
+        for outcome in custom_outcomes.values() {
+            let points = match outcome {
+                PolicyOutcome::Match(points, _info) => *points,
+                PolicyOutcome::Warning(points, _reason) => *points,
+                PolicyOutcome::NoMatch(_reason) => 0.0,
+            };
+            total_points += points;
+            total_count += 1;
+            *category_points.entry(MetricCategory::Custom).or_insert(0.0) += points;
+            *category_counts.entry(MetricCategory::Custom).or_insert(0) += 1;
+        }
+
+        let score = if total_count == 0 {
             0.0
         } else {
             #[expect(clippy::cast_precision_loss, reason = "Precision loss acceptable for score calculation")]
-            let avg = total_points / policy_outcomes.len() as f64;
+            let avg = total_points / total_count as f64;
             (avg * 100.0).round() / 100.0
         };
 
@@ -78,11 +131,24 @@ impl<'a> Ranker<'a> {
             }
         }
 
+        let health_score = aggregate_score(&score_outcomes, self.aggregation_mode);
+        let risk_level = if let (ProviderResult::Found(overall), ProviderResult::Found(version_data)) =
+            (&facts.crate_overall_data, &facts.crate_version_data)
+        {
+            Some(self.config.classify_risk(&overall.name, &version_data.version, health_score))
+        } else {
+            None
+        };
+
         RankingOutcome {
             overall_score: score,
             category_scores,
             details: policy_outcomes.clone(),
+            custom_details: custom_outcomes,
+            health_score,
+            scores: score_outcomes.clone(),
             dependency_type,
+            risk_level,
         }
     }
 }
@@ -0,0 +1,19 @@
+//! Risk classification derived from a crate's health score.
+
+use serde::{Deserialize, Serialize};
+use strum::Display;
+
+/// Where a crate's `health_score` falls relative to [`crate::config::Config::medium_risk_threshold`]/
+/// [`crate::config::Config::low_risk_threshold`], or whether a current
+/// [`crate::config::Config::allow_list`] entry exempts it from being flagged at all.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display)]
+#[serde(rename_all = "snake_case")]
+pub enum RiskLevel {
+    Low,
+    Medium,
+    High,
+
+    /// Would otherwise be `Medium` or `High`, but a current `allow_list` entry exempts this
+    /// crate+version.
+    Exempt,
+}
@@ -0,0 +1,17 @@
+//! Continuous, weighted scoring result type.
+
+/// A metric's normalized contribution to the crate's aggregate health score.
+///
+/// Complements [`super::PolicyOutcome`]'s discrete pass/fail with a continuous measure, so
+/// crates can be ranked/sorted rather than only gated. Metrics whose provider data was
+/// missing simply have no entry rather than a score of `0.0`, so they don't drag the
+/// aggregate down. Most metrics with no applicable policy are still scored, against a
+/// threshold of `0`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScoreOutcome {
+    /// Normalized sub-score in `[0, 1]`.
+    pub sub_score: f64,
+
+    /// Relative weight of this metric in the aggregate score.
+    pub weight: f64,
+}
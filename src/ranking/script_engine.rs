@@ -0,0 +1,180 @@
+//! Org-specific metrics expressed as sandboxed Rhai scripts (`Config::custom_metrics`).
+//!
+//! Each [`crate::config::ScriptPolicy`] is compiled once per run into an [`rhai::AST`]; scripts
+//! never touch the filesystem or network (Rhai has no such built-ins to begin with), and a
+//! bounded operation count guards against an accidental infinite loop. A script sees a
+//! read-only `facts` object and a `dependency_type` string, and is expected to return either a
+//! bare number of points, or a `#{points: .., reason: ..}` map pairing points with a reason
+//! string. Anything else — a type mismatch, a runtime error, a missing `facts` field — is
+//! reported as a [`PolicyOutcome::NoMatch`] rather than aborting the scan.
+
+use crate::config::{Config, ScriptPolicy};
+use crate::facts::CrateFacts;
+use crate::misc::{DependencyType, DependencyTypes};
+use crate::ranking::PolicyOutcome;
+use rhai::{Dynamic, Engine, Map, Scope, AST};
+use std::collections::HashMap;
+
+/// Operation budget for a single script evaluation, guarding against an accidental infinite
+/// loop in org-supplied Rhai.
+const MAX_OPERATIONS: u64 = 1_000_000;
+
+/// A [`ScriptPolicy`] compiled once and ready to re-evaluate per crate.
+#[derive(Debug)]
+struct CompiledScript {
+    name: String,
+    dependency_types: DependencyTypes,
+    ast: AST,
+}
+
+/// Compiles and runs `Config::custom_metrics` against each crate's [`CrateFacts`].
+#[derive(Debug)]
+pub struct ScriptEngine {
+    engine: Engine,
+    scripts: Vec<CompiledScript>,
+}
+
+impl ScriptEngine {
+    /// Compile every configured `custom_metrics` script once, up front.
+    ///
+    /// A script that fails to compile is dropped with its syntax error folded into a
+    /// permanent [`PolicyOutcome::NoMatch`] for every crate, rather than aborting the run.
+    #[must_use]
+    pub fn compile(config: &Config) -> Self {
+        let mut engine = Engine::new();
+        engine.set_max_operations(MAX_OPERATIONS);
+        engine.disable_symbol("eval");
+
+        let mut scripts = Vec::with_capacity(config.custom_metrics.len());
+        for policy in &config.custom_metrics {
+            match engine.compile(&policy.script) {
+                Ok(ast) => scripts.push(CompiledScript {
+                    name: policy.name.clone(),
+                    dependency_types: policy.dependency_types.clone(),
+                    ast,
+                }),
+                Err(err) => scripts.push(CompiledScript {
+                    name: policy.name.clone(),
+                    dependency_types: policy.dependency_types.clone(),
+                    ast: broken_script_ast(&engine, err.to_string()),
+                }),
+            }
+        }
+
+        Self { engine, scripts }
+    }
+
+    /// Evaluate every script whose `dependency_types` applies to `dependency_type`, keyed by
+    /// [`ScriptPolicy::name`].
+    #[must_use]
+    pub fn evaluate(&self, facts: &CrateFacts, dependency_type: DependencyType) -> HashMap<String, PolicyOutcome> {
+        let mut outcomes = HashMap::with_capacity(self.scripts.len());
+        for script in &self.scripts {
+            if !script.dependency_types.contains(dependency_type) {
+                continue;
+            }
+
+            let mut scope = Scope::new();
+            scope.push_constant("facts", facts_to_dynamic(facts));
+            scope.push_constant("dependency_type", dependency_type.to_string());
+
+            let outcome = match self.engine.eval_ast_with_scope::<Dynamic>(&mut scope, &script.ast) {
+                Ok(value) => dynamic_to_outcome(&value),
+                Err(err) => PolicyOutcome::NoMatch(format!("script error: {err}")),
+            };
+            let _ = outcomes.insert(script.name.clone(), outcome);
+        }
+        outcomes
+    }
+}
+
+/// An `AST` that always raises `message` as a runtime error, used to keep a script that failed
+/// to compile represented uniformly as a [`CompiledScript`].
+fn broken_script_ast(engine: &Engine, message: String) -> AST {
+    let escaped = message.replace('\\', "\\\\").replace('"', "\\\"");
+    engine.compile(format!("throw \"{escaped}\";")).unwrap_or_else(|_| engine.compile("throw \"invalid script\";").expect("trivial script"))
+}
+
+/// Interpret a script's return value per [`ScriptEngine`]'s documented contract.
+fn dynamic_to_outcome(value: &Dynamic) -> PolicyOutcome {
+    if let Some(points) = value.as_float().ok().or_else(|| value.as_int().ok().map(|i| i as f64)) {
+        return PolicyOutcome::Match(points, String::new());
+    }
+
+    if let Some(map) = value.read_lock::<Map>() {
+        let points = map
+            .get("points")
+            .and_then(|p| p.as_float().ok().or_else(|| p.as_int().ok().map(|i| i as f64)));
+        let reason = map.get("reason").and_then(|r| r.clone().into_string().ok()).unwrap_or_default();
+        return match points {
+            Some(points) => PolicyOutcome::Match(points, reason),
+            None => PolicyOutcome::NoMatch(if reason.is_empty() { "script returned no 'points'".to_string() } else { reason }),
+        };
+    }
+
+    PolicyOutcome::NoMatch(format!("script returned unsupported type '{}'", value.type_name()))
+}
+
+/// Build the read-only `facts` object bound into a script's scope: advisory counts, hosting
+/// commit/star/fork stats, the owner list, and docs metrics, matching `Config::custom_metrics`'s
+/// documented contract.
+fn facts_to_dynamic(facts: &CrateFacts) -> Dynamic {
+    let mut root = Map::new();
+
+    if let Some(advisories) = facts.advisory_data.clone().ok() {
+        let mut m = Map::new();
+        let _ = m.insert("vulnerability_count".into(), (advisories.vulnerability_count as i64).into());
+        let _ = m.insert("low_vulnerability_count".into(), (advisories.low_vulnerability_count as i64).into());
+        let _ = m.insert("medium_vulnerability_count".into(), (advisories.medium_vulnerability_count as i64).into());
+        let _ = m.insert("high_vulnerability_count".into(), (advisories.high_vulnerability_count as i64).into());
+        let _ = m.insert("critical_vulnerability_count".into(), (advisories.critical_vulnerability_count as i64).into());
+        let _ = m.insert("cvss_weighted_vulnerability_score".into(), advisories.cvss_weighted_vulnerability_score.into());
+        let _ = root.insert("advisories".into(), m.into());
+    }
+
+    if let Some(hosting) = facts.hosting_data.clone().ok() {
+        let mut m = Map::new();
+        let _ = m.insert("stars".into(), (hosting.stars as i64).into());
+        let _ = m.insert("forks".into(), (hosting.forks as i64).into());
+        let _ = m.insert("subscribers".into(), (hosting.subscribers as i64).into());
+        let _ = m.insert("contributors".into(), (hosting.contributors as i64).into());
+        let _ = m.insert("commits_last_3_months".into(), (hosting.commits_last_3_months as i64).into());
+        let _ = root.insert("hosting".into(), m.into());
+    }
+
+    if let Some(overall) = facts.crate_overall_data.clone().ok() {
+        let owners: Dynamic = overall
+            .owners
+            .iter()
+            .map(|owner| {
+                let mut m = Map::new();
+                let _ = m.insert("login".into(), owner.login.clone().into());
+                let kind = match owner.kind {
+                    crate::facts::OwnerKind::User => "user",
+                    crate::facts::OwnerKind::Team => "team",
+                };
+                let _ = m.insert("kind".into(), kind.into());
+                let _ = m.insert("name".into(), owner.name.clone().unwrap_or_default().into());
+                Dynamic::from(m)
+            })
+            .collect::<Vec<_>>()
+            .into();
+        let _ = root.insert("owners".into(), owners);
+        let _ = root.insert("created_at".into(), overall.created_at.to_rfc3339().into());
+    }
+
+    if let Some(docs) = facts.docs_data.clone().ok()
+        && let crate::facts::docs::MetricState::Found(metrics) = docs.state
+    {
+        let mut m = Map::new();
+        let _ = m.insert("doc_coverage_percentage".into(), (metrics.doc_coverage_percentage as i64).into());
+        let _ = m.insert("number_of_public_api_elements".into(), (metrics.number_of_public_api_elements as i64).into());
+        let _ = m.insert("number_of_undocumented_elements".into(), (metrics.number_of_undocumented_elements as i64).into());
+        let _ = m.insert("number_of_examples_in_docs".into(), (metrics.number_of_examples_in_docs as i64).into());
+        let _ = m.insert("has_crate_level_docs".into(), metrics.has_crate_level_docs.into());
+        let _ = m.insert("broken_doc_links".into(), (metrics.broken_doc_links as i64).into());
+        let _ = root.insert("docs".into(), m.into());
+    }
+
+    root.into()
+}
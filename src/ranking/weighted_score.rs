@@ -0,0 +1,39 @@
+//! Aggregation of per-metric [`ScoreOutcome`]s into a single 0-100 health score.
+
+use crate::metrics::Metric;
+use crate::ranking::ScoreOutcome;
+use core::hash::BuildHasher;
+use std::collections::HashMap;
+
+/// How surviving sub-scores are combined into the aggregate score.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AggregationMode {
+    /// Weighted arithmetic mean: `sum(score * weight) / sum(weight)`.
+    WeightedMean,
+
+    /// Weighted geometric mean, so one catastrophically low sub-score drags the total down
+    /// rather than being smoothed out by the rest.
+    WeightedGeometricMean,
+}
+
+/// Combines every recorded [`ScoreOutcome`] into a single score in `[0, 100]`.
+///
+/// Returns `0.0` if no sub-scores were recorded, e.g. every metric's provider data was
+/// missing.
+#[must_use]
+pub fn aggregate_score<S: BuildHasher>(scores: &HashMap<Metric, ScoreOutcome, S>, mode: AggregationMode) -> f64 {
+    let total_weight: f64 = scores.values().map(|s| s.weight).sum();
+    if total_weight <= 0.0 {
+        return 0.0;
+    }
+
+    let combined = match mode {
+        AggregationMode::WeightedMean => scores.values().map(|s| s.sub_score * s.weight).sum::<f64>() / total_weight,
+        AggregationMode::WeightedGeometricMean => {
+            let log_sum: f64 = scores.values().map(|s| s.weight * s.sub_score.max(f64::EPSILON).ln()).sum();
+            (log_sum / total_weight).exp()
+        }
+    };
+
+    (combined * 100.0).clamp(0.0, 100.0)
+}
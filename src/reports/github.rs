@@ -0,0 +1,42 @@
+//! GitHub Actions workflow-command annotations.
+//!
+//! Unlike the other report formats, this one is meant to be read by GitHub's problem matcher
+//! rather than a human or a monitoring system: each line is a `::warning ...` / `::error ...`
+//! workflow command that GitHub turns into an inline PR annotation on the referenced file.
+
+use crate::facts::CrateSpec;
+use crate::ranking::{PolicyOutcome, RankingOutcome};
+
+/// Renders `outcomes` as GitHub Actions workflow-command annotations, one per
+/// [`PolicyOutcome::NoMatch`] and [`PolicyOutcome::Warning`] — an `::error` for the former, a
+/// `::warning` for the latter, matching `PolicyOutcome`'s unmatched-is-disqualifying semantics.
+/// Each command is keyed to the crate's `Cargo.toml`, since that's the file a reviewer would
+/// edit to change the dependency.
+#[must_use]
+pub fn generate(outcomes: &[(CrateSpec, RankingOutcome)]) -> String {
+    let mut out = String::new();
+
+    for (spec, outcome) in outcomes {
+        for (metric, policy_outcome) in &outcome.details {
+            let (command, reason) = match policy_outcome {
+                PolicyOutcome::NoMatch(reason) => ("error", reason),
+                PolicyOutcome::Warning(_, reason) => ("warning", reason),
+                PolicyOutcome::Match(..) => continue,
+            };
+
+            out.push_str(&format!(
+                "::{command} file=Cargo.toml,title={} {} failed {metric}::{}\n",
+                spec.name(),
+                spec.version(),
+                escape(reason),
+            ));
+        }
+    }
+
+    out
+}
+
+/// Escapes a workflow-command property/message per GitHub's `%`/`\r`/`\n` escaping rules.
+fn escape(value: &str) -> String {
+    value.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A")
+}
@@ -1,7 +1,15 @@
 mod console;
 mod excel;
+mod github;
 mod html;
+mod prometheus;
+mod sarif;
+mod sparkline;
 
 pub use console::generate as generate_console;
 pub use excel::generate as generate_xlsx;
+pub use github::generate as generate_github;
 pub use html::generate as generate_html;
+pub use prometheus::generate as generate_prometheus;
+pub use sarif::generate as generate_sarif;
+pub use sparkline::generate as generate_download_sparkline;
@@ -0,0 +1,156 @@
+//! OpenMetrics/Prometheus exposition of per-crate, per-metric policy outcomes.
+//!
+//! Unlike the other report formats, this one isn't meant to be read directly — it's scraped
+//! (or pushed) into a monitoring system so a CI job can diff a dependency's gauges across runs
+//! and alert when its score regresses, the way service-health dashboards track per-peer gauges
+//! over time.
+
+use crate::facts::CrateSpec;
+use crate::metrics::Metric;
+use crate::ranking::{PolicyOutcome, RankingOutcome};
+use std::fmt::Write as _;
+
+/// Labels identifying a single per-metric gauge sample.
+struct MetricLabels {
+    crate_name: String,
+    version: String,
+    dependency_type: String,
+    metric: String,
+    category: String,
+}
+
+/// Renders one gauge family (a metric name shared by every sample, distinguished only by
+/// label set) as OpenMetrics text exposition format, mirroring a `Family<Labels, Gauge>`
+/// registry: `samples` pairs each label set with the gauge's current value.
+fn write_gauge_family(out: &mut String, name: &str, help: &str, samples: &[(MetricLabels, f64)]) {
+    if samples.is_empty() {
+        return;
+    }
+
+    let _ = writeln!(out, "# HELP {name} {help}");
+    let _ = writeln!(out, "# TYPE {name} gauge");
+    for (labels, value) in samples {
+        let _ = writeln!(
+            out,
+            "{name}{{crate=\"{}\",version=\"{}\",dependency_type=\"{}\",metric=\"{}\",category=\"{}\"}} {value}",
+            escape(&labels.crate_name),
+            escape(&labels.version),
+            labels.dependency_type,
+            labels.metric,
+            labels.category,
+        );
+    }
+}
+
+/// Escapes a label value per the OpenMetrics text format: backslash and double-quote are
+/// backslash-escaped, and newlines become `\n`.
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+/// Renders `outcomes` — one [`RankingOutcome`] per evaluated crate, paired with the
+/// [`CrateSpec`] it was computed for — as OpenMetrics text exposition format.
+///
+/// Every `Metric`/[`PolicyOutcome`] pair becomes a sample in two gauge families, both labeled
+/// by crate name, version, dependency type, metric, and the metric's category:
+/// - `cargo_aprz_metric_points`: the policy's scaled points (`0.0` on no-match).
+/// - `cargo_aprz_metric_matched`: `1` if the policy matched, `0` otherwise (a `Warning`
+///   outcome counts as unmatched here, even though it still contributes points).
+///
+/// Each crate's aggregate pass/fail score becomes a single `cargo_aprz_overall_score` gauge,
+/// labeled by crate name, version, and dependency type.
+#[must_use]
+pub fn generate(outcomes: &[(CrateSpec, RankingOutcome)]) -> String {
+    let mut points_samples = Vec::new();
+    let mut matched_samples = Vec::new();
+    let mut score_samples = Vec::new();
+
+    for (spec, outcome) in outcomes {
+        let dependency_type = outcome.dependency_type.to_string();
+
+        for (metric, policy_outcome) in &outcome.details {
+            let make_labels = || MetricLabels {
+                crate_name: spec.name().to_string(),
+                version: spec.version().to_string(),
+                dependency_type: dependency_type.clone(),
+                metric: metric_label(*metric),
+                category: metric.category().to_string(),
+            };
+
+            let (points, matched) = match policy_outcome {
+                PolicyOutcome::Match(points, _) => (*points, 1.0),
+                PolicyOutcome::Warning(points, _) => (*points, 0.0),
+                PolicyOutcome::NoMatch(_) => (0.0, 0.0),
+            };
+
+            points_samples.push((make_labels(), points));
+            matched_samples.push((make_labels(), matched));
+        }
+
+        score_samples.push((
+            MetricLabels {
+                crate_name: spec.name().to_string(),
+                version: spec.version().to_string(),
+                dependency_type,
+                metric: String::new(),
+                category: String::new(),
+            },
+            outcome.overall_score,
+        ));
+    }
+
+    let mut out = String::new();
+    write_gauge_family(
+        &mut out,
+        "cargo_aprz_metric_points",
+        "Scaled points awarded by a crate's policy outcome for a metric.",
+        &points_samples,
+    );
+    write_gauge_family(
+        &mut out,
+        "cargo_aprz_metric_matched",
+        "Whether a crate's policy outcome for a metric matched (1) or not (0).",
+        &matched_samples,
+    );
+
+    if !score_samples.is_empty() {
+        let _ = writeln!(out, "# HELP cargo_aprz_overall_score A crate's aggregate pass/fail policy score.");
+        let _ = writeln!(out, "# TYPE cargo_aprz_overall_score gauge");
+        for (labels, value) in &score_samples {
+            let _ = writeln!(
+                out,
+                "cargo_aprz_overall_score{{crate=\"{}\",version=\"{}\",dependency_type=\"{}\"}} {value}",
+                escape(&labels.crate_name),
+                escape(&labels.version),
+                labels.dependency_type,
+            );
+        }
+    }
+
+    let risk_samples: Vec<_> =
+        outcomes.iter().filter_map(|(spec, outcome)| Some((spec, outcome.dependency_type, outcome.risk_level?))).collect();
+    if !risk_samples.is_empty() {
+        let _ = writeln!(
+            out,
+            "# HELP cargo_aprz_risk_level A crate's risk classification against `medium_risk_threshold`/`low_risk_threshold` (1 = applies)."
+        );
+        let _ = writeln!(out, "# TYPE cargo_aprz_risk_level gauge");
+        for (spec, dependency_type, risk_level) in risk_samples {
+            let _ = writeln!(
+                out,
+                "cargo_aprz_risk_level{{crate=\"{}\",version=\"{}\",dependency_type=\"{dependency_type}\",level=\"{risk_level}\"}} 1",
+                escape(spec.name()),
+                escape(&spec.version().to_string()),
+            );
+        }
+    }
+
+    out.push_str("# EOF\n");
+    out
+}
+
+/// `Metric`'s `Display` impl already renders `snake_case`, matching OpenMetrics label
+/// conventions, so this is just a named hook for clarity at call sites.
+fn metric_label(metric: Metric) -> String {
+    metric.to_string()
+}
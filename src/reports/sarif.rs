@@ -0,0 +1,99 @@
+//! SARIF (Static Analysis Results Interchange Format) report backend.
+//!
+//! Unlike the other report formats, this one is meant for consumption by code-scanning
+//! tooling (e.g. GitHub's `upload-sarif` action) rather than a human: it emits a standard
+//! SARIF 2.1.0 JSON document with one `result` per [`PolicyOutcome::NoMatch`], so policy
+//! failures show up as code-scanning alerts on the crate's `Cargo.toml`.
+
+use crate::facts::CrateSpec;
+use crate::ranking::{PolicyOutcome, RankingOutcome};
+use std::fmt::Write as _;
+
+/// Renders `outcomes` as a SARIF 2.1.0 log with a single run, one `result` per
+/// [`PolicyOutcome::NoMatch`]. The rule id is the metric's `snake_case` name; the message
+/// is the policy's failure reason; the `physicalLocation` points at the crate's `Cargo.toml`.
+#[must_use]
+pub fn generate(outcomes: &[(CrateSpec, RankingOutcome)]) -> String {
+    let mut results = String::new();
+    let mut first = true;
+
+    for (spec, outcome) in outcomes {
+        for (metric, policy_outcome) in &outcome.details {
+            let PolicyOutcome::NoMatch(reason) = policy_outcome else {
+                continue;
+            };
+
+            if !first {
+                results.push_str(",\n");
+            }
+            first = false;
+
+            let _ = write!(
+                results,
+                r#"        {{
+          "ruleId": "{metric}",
+          "level": "error",
+          "message": {{ "text": "{} {}: {}" }},
+          "locations": [
+            {{
+              "physicalLocation": {{
+                "artifactLocation": {{ "uri": "Cargo.toml" }}
+              }}
+            }}
+          ]
+        }}"#,
+                escape(spec.name()),
+                escape(&spec.version().to_string()),
+                escape(reason),
+            );
+        }
+    }
+
+    format!(
+        r#"{{
+  "$schema": "https://raw.githubusercontent.com/oasis-tcs/sarif-spec/master/Schemata/sarif-schema-2.1.0.json",
+  "version": "2.1.0",
+  "runs": [
+    {{
+      "tool": {{
+        "driver": {{
+          "name": "cargo-aprz",
+          "informationUri": "https://github.com/geeknoid/cargo-aprz",
+          "rules": []
+        }}
+      }},
+      "results": [
+{results}
+      ]
+    }}
+  ]
+}}
+"#
+    )
+}
+
+/// Escapes a string for embedding in a JSON string literal, per RFC 8259 section 7: `\` and `"` are
+/// backslash-escaped, the common control characters get their short escapes, and every other
+/// control character (`U+0000..=U+001F`) falls back to a `\u00XX` escape. Unlike
+/// `crate::reports::prometheus::escape`, this can't stop at `\n` alone: a literal `\r` or tab
+/// left unescaped produces invalid JSON, whereas Prometheus's label-value format doesn't allow
+/// embedded control characters to begin with.
+fn escape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '"' => out.push_str("\\\""),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{08}' => out.push_str("\\b"),
+            '\u{0C}' => out.push_str("\\f"),
+            c if c.is_control() => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out
+}
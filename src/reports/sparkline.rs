@@ -0,0 +1,62 @@
+//! Inline SVG download-history sparkline, styled via the configurable [`Color`] type.
+//!
+//! Renders a crate's `monthly_downloads` series as a small log-scaled area/line chart meant
+//! to be embedded directly inside HTML reports, similar to the downloads graph on a crate's
+//! crates.io page.
+
+use crate::config::Color;
+use chrono::NaiveDate;
+
+const WIDTH: f64 = 240.0;
+const HEIGHT: f64 = 40.0;
+
+/// Renders `monthly_downloads` (oldest first) as an inline `<svg>` sparkline, stroked in
+/// `color`.
+///
+/// Download counts are log-scaled (`ln(1 + count)`) so a handful of outlier months don't
+/// flatten the rest of the series. Degrades gracefully: an empty series renders nothing, and
+/// a single-point series renders a flat centered line rather than a degenerate chart.
+#[must_use]
+pub fn generate(monthly_downloads: &[(NaiveDate, u64)], color: Color) -> String {
+    if monthly_downloads.is_empty() {
+        return String::new();
+    }
+
+    let stroke = to_hex(color);
+
+    if monthly_downloads.len() == 1 {
+        let half = HEIGHT / 2.0;
+        return format!(
+            r#"<svg width="{WIDTH}" height="{HEIGHT}" viewBox="0 0 {WIDTH} {HEIGHT}" xmlns="http://www.w3.org/2000/svg" role="img" aria-label="flat download history"><line x1="0" y1="{half}" x2="{WIDTH}" y2="{half}" stroke="{stroke}" stroke-width="2"/></svg>"#
+        );
+    }
+
+    #[expect(clippy::cast_precision_loss, reason = "chart coordinate math, not numeric precision sensitive")]
+    let scaled: Vec<f64> = monthly_downloads.iter().map(|(_, count)| (*count as f64 + 1.0).ln()).collect();
+    let min = scaled.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = scaled.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let span = (max - min).max(f64::EPSILON);
+
+    #[expect(clippy::cast_precision_loss, reason = "chart coordinate math, not numeric precision sensitive")]
+    let polyline = scaled
+        .iter()
+        .enumerate()
+        .map(|(i, &v)| {
+            let x = (i as f64 / (scaled.len() - 1) as f64) * WIDTH;
+            let y = HEIGHT - ((v - min) / span) * HEIGHT;
+            format!("{x:.1},{y:.1}")
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let area = format!("{polyline} {WIDTH:.1},{HEIGHT:.1} 0.0,{HEIGHT:.1}");
+
+    format!(
+        r#"<svg width="{WIDTH}" height="{HEIGHT}" viewBox="0 0 {WIDTH} {HEIGHT}" xmlns="http://www.w3.org/2000/svg" role="img" aria-label="download history"><polygon points="{area}" fill="{stroke}" fill-opacity="0.15" stroke="none"/><polyline points="{polyline}" fill="none" stroke="{stroke}" stroke-width="2"/></svg>"#
+    )
+}
+
+/// Renders a [`Color`] as a `#rrggbb` CSS/SVG hex string.
+fn to_hex(color: Color) -> String {
+    format!("#{:02x}{:02x}{:02x}", color.0.red, color.0.green, color.0.blue)
+}
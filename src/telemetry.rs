@@ -0,0 +1,99 @@
+//! Cross-cutting counters/histograms for the fact-gathering providers, rendered as a one-shot
+//! Prometheus text dump at the end of a run.
+//!
+//! [`crate::facts::advisories::Provider`] is the only call site: its sync/open timings and scan
+//! loop's count + duration are recorded under the `advisories` provider label, taken from that
+//! module's `LOG_TARGET` constant. No other provider records into a [`Telemetry`] instance.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Default)]
+struct Counter {
+    value: u64,
+}
+
+/// Count/sum/min/max over recorded durations for one (metric name, provider) pair. Plain
+/// summary stats rather than configurable histogram buckets, which is enough for a one-shot
+/// end-of-run dump.
+#[derive(Debug, Default)]
+struct Histogram {
+    count: u64,
+    sum: Duration,
+    min: Option<Duration>,
+    max: Option<Duration>,
+}
+
+impl Histogram {
+    fn record(&mut self, duration: Duration) {
+        self.count += 1;
+        self.sum += duration;
+        self.min = Some(self.min.map_or(duration, |min| min.min(duration)));
+        self.max = Some(self.max.map_or(duration, |max| max.max(duration)));
+    }
+}
+
+/// Thread-safe counters and duration histograms for the fact-gathering pipeline, labeled by
+/// metric name and provider (e.g. `advisories`).
+#[derive(Debug, Default)]
+pub struct Telemetry {
+    counters: Mutex<HashMap<(&'static str, String), Counter>>,
+    histograms: Mutex<HashMap<(&'static str, String), Histogram>>,
+}
+
+impl Telemetry {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Increment `name` (e.g. `advisories_checked`, `cache_hits`) labeled by `provider` by
+    /// `by`.
+    pub fn increment(&self, name: &'static str, provider: &str, by: u64) {
+        let mut counters = self.counters.lock().expect("lock poisoned");
+        counters.entry((name, provider.to_string())).or_default().value += by;
+    }
+
+    /// Record a single duration sample for `name` labeled by `provider`.
+    pub fn record_duration(&self, name: &'static str, provider: &str, duration: Duration) {
+        let mut histograms = self.histograms.lock().expect("lock poisoned");
+        histograms.entry((name, provider.to_string())).or_default().record(duration);
+    }
+
+    /// Run `operation`, recording its wall-clock duration under `name`/`provider` regardless of
+    /// whether it succeeds, and returning its result unchanged.
+    pub fn time<T>(&self, name: &'static str, provider: &str, operation: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let result = operation();
+        self.record_duration(name, provider, start.elapsed());
+        result
+    }
+
+    /// Render every counter and histogram in Prometheus text exposition format, suitable for a
+    /// one-shot dump at the end of a run or for a short-lived scrape endpoint to serve as-is.
+    #[must_use]
+    pub fn render_prometheus(&self) -> String {
+        let mut out = String::new();
+
+        let counters = self.counters.lock().expect("lock poisoned");
+        for ((name, provider), counter) in counters.iter() {
+            let _ = writeln!(out, "cargo_aprz_{name}_total{{provider=\"{provider}\"}} {}", counter.value);
+        }
+
+        let histograms = self.histograms.lock().expect("lock poisoned");
+        for ((name, provider), histogram) in histograms.iter() {
+            let _ = writeln!(out, "cargo_aprz_{name}_duration_seconds_count{{provider=\"{provider}\"}} {}", histogram.count);
+            let _ = writeln!(out, "cargo_aprz_{name}_duration_seconds_sum{{provider=\"{provider}\"}} {:.6}", histogram.sum.as_secs_f64());
+            if let Some(min) = histogram.min {
+                let _ = writeln!(out, "cargo_aprz_{name}_duration_seconds_min{{provider=\"{provider}\"}} {:.6}", min.as_secs_f64());
+            }
+            if let Some(max) = histogram.max {
+                let _ = writeln!(out, "cargo_aprz_{name}_duration_seconds_max{{provider=\"{provider}\"}} {:.6}", max.as_secs_f64());
+            }
+        }
+
+        out
+    }
+}